@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use crate::application::predicators::{PredictionConfig, StockPredictor};
+use crate::application::prediction_service::PredictionService;
+use crate::domain::forecast_metrics::{ForecastEvaluator, ForecastMetrics};
+use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::time_series::TimeInterval;
+
+/// Nombre maximal d'origines rejouées par méthode ; au-delà, l'écart entre origines est élargi
+pub const MAX_FOLDS: usize = 200;
+
+/// Fenêtre d'apprentissage du walk-forward
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WindowMode {
+    /// Tout l'historique disponible jusqu'à l'origine de la prévision
+    Expanding,
+    /// Les `train_size` derniers points seulement
+    Rolling,
+}
+
+#[derive(Debug, Clone)]
+pub struct BacktestSettings {
+    pub window: WindowMode,
+    /// Taille minimale (expanding) ou fixe (rolling) de la fenêtre d'apprentissage
+    pub train_size: usize,
+    /// Nombre de points prévus à chaque origine
+    pub horizon: usize,
+    /// Décalage entre deux origines successives
+    pub stride: usize,
+    pub confidence: f64,
+    pub interval: TimeInterval,
+    /// Hyperparamètres transmis au prédicteur à chaque origine
+    pub parameters: BTreeMap<String, f64>,
+}
+
+impl BacktestSettings {
+    /// Écart effectif entre origines : celui demandé, élargi pour ne pas dépasser `MAX_FOLDS` origines
+    pub fn effective_stride(&self, history_len: usize) -> usize {
+        let origins = history_len.saturating_sub(self.train_size.max(1));
+        self.stride.max(1).max(origins.div_ceil(MAX_FOLDS))
+    }
+
+    /// Nombre d'origines rejouées sur un historique de `history_len` points
+    pub fn fold_count(&self, history_len: usize) -> usize {
+        history_len
+            .saturating_sub(self.train_size.max(1))
+            .div_ceil(self.effective_stride(history_len))
+    }
+}
+
+/// Résultat du backtest d'une méthode
+#[derive(Debug, Clone, Serialize)]
+pub struct Scorecard {
+    pub method: String,
+    /// Nombre d'origines de prévision rejouées et notées
+    pub folds: usize,
+    pub metrics: ForecastMetrics,
+}
+
+pub struct BacktestService {
+    prediction_service: Arc<PredictionService>,
+}
+
+impl BacktestService {
+    pub fn new(prediction_service: Arc<PredictionService>) -> Self {
+        Self { prediction_service }
+    }
+
+    /// Rejoue l'historique pour chaque méthode demandée (toutes si la liste est vide).
+    /// Le calcul, intensif, tourne sur les threads bloquants plutôt que sur l'exécuteur asynchrone.
+    pub async fn run(&self, history: &[PredictionPoint], methods: &[String], settings: &BacktestSettings) -> Vec<Scorecard> {
+        let methods = if methods.is_empty() { self.prediction_service.methods() } else { methods.to_vec() };
+        let history: Arc<[PredictionPoint]> = history.into();
+
+        let tasks: Vec<_> = methods
+            .iter()
+            .filter_map(|method| self.prediction_service.find(method))
+            .map(|predictor| {
+                let (predictor, history, settings) = (predictor.clone(), history.clone(), settings.clone());
+                tokio::task::spawn_blocking(move || {
+                    futures::executor::block_on(Self::evaluate(predictor.as_ref(), &history, &settings))
+                })
+            })
+            .collect();
+
+        let mut scorecards = Vec::new();
+        for task in tasks {
+            match task.await {
+                Ok(scorecard) => scorecards.push(scorecard),
+                Err(err) => eprintln!("Backtest interrompu : {:?}", err),
+            }
+        }
+        scorecards
+    }
+
+    /// Walk-forward d'un prédicteur : à chaque origine, apprentissage sur la fenêtre
+    /// puis comparaison des `horizon` points prévus aux points réellement observés
+    pub async fn evaluate(predictor: &dyn StockPredictor, history: &[PredictionPoint], settings: &BacktestSettings) -> Scorecard {
+        let mut evaluator = ForecastEvaluator::new();
+        let mut folds = 0;
+        let stride = settings.effective_stride(history.len());
+
+        let mut origin = settings.train_size.max(1);
+        while origin < history.len() {
+            let start = match settings.window {
+                WindowMode::Expanding => 0,
+                WindowMode::Rolling => origin.saturating_sub(settings.train_size),
+            };
+            let train = &history[start..origin];
+            let actual = &history[origin..(origin + settings.horizon).min(history.len())];
+
+            let mut config = PredictionConfig::new(settings.confidence, actual.len(), 1, settings.interval.clone());
+            config.parameters = settings.parameters.clone();
+            let forecast = predictor.predict(train, &config).await;
+            let reference = train.last().map(|p| p.close).unwrap_or(0.0);
+
+            for (predicted, real) in forecast.iter().zip(actual) {
+                evaluator.record(predicted, real.close, reference);
+            }
+
+            // Une origine sans prévision n'est pas notée
+            if !forecast.is_empty() {
+                folds += 1;
+            }
+            origin += stride;
+        }
+
+        Scorecard {
+            method: predictor.method_name().to_string(),
+            folds,
+            metrics: evaluator.metrics(),
+        }
+    }
+}
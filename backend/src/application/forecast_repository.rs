@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use crate::domain::forecast_record::ForecastRecord;
+
+#[async_trait]
+pub trait ForecastRepository: Send + Sync {
+    /// Insère la prévision ou remplace celle de même identifiant
+    async fn save_forecast(&self, record: &ForecastRecord) -> anyhow::Result<()>;
+    /// Au plus `limit` prévisions dont certains points n'ont pas encore été confrontés au réel,
+    /// par identifiant croissant et strictement après `after`
+    async fn pending_forecasts(&self, after: Option<&str>, limit: usize) -> anyhow::Result<Vec<ForecastRecord>>;
+    /// Page de prévisions d'un symbole, les plus récentes d'abord, éventuellement restreinte à une méthode
+    async fn list_forecasts(&self, symbol: &str, method: Option<&str>, offset: usize, limit: usize) -> anyhow::Result<Vec<ForecastRecord>>;
+    /// Au plus `limit` prévisions ayant au moins un point réalisé, les plus récentes d'abord
+    async fn realised_forecasts(&self, symbol: Option<&str>, limit: usize) -> anyhow::Result<Vec<ForecastRecord>>;
+}
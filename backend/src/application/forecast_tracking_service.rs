@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use serde::Serialize;
+use crate::application::forecast_repository::ForecastRepository;
+use crate::application::predicators::PredictionConfig;
+use crate::application::stock_repository::StockRepository;
+use crate::domain::forecast::Forecast;
+use crate::domain::forecast_record::{ForecastRecord, Leaderboard};
+use crate::domain::prediction_point::PredictionPoint;
+
+/// Prévisions réalisées les plus récentes retenues pour le classement
+pub const LEADERBOARD_RECORDS: usize = 5000;
+
+/// Prévisions en attente chargées à la fois par le job d'évaluation
+pub const PENDING_BATCH: usize = 500;
+
+/// Bilan d'un passage du job d'évaluation
+#[derive(Debug, Default, Serialize)]
+pub struct EvaluationReport {
+    /// Prévisions en attente examinées
+    pub pending: usize,
+    /// Prévisions ayant reçu de nouveaux cours réalisés
+    pub updated: usize,
+    /// Prévisions désormais entièrement réalisées
+    pub completed: usize,
+    /// Points nouvellement confrontés au réel
+    pub points: usize,
+}
+
+/// Archive des prévisions servies et suivi de leur erreur réalisée, pour juger les méthodes
+/// sur leur précision hors échantillon réelle
+pub struct ForecastTrackingService {
+    forecasts: Arc<dyn ForecastRepository>,
+    stocks: Arc<dyn StockRepository>,
+}
+
+impl ForecastTrackingService {
+    pub fn new(forecasts: Arc<dyn ForecastRepository>, stocks: Arc<dyn StockRepository>) -> Self {
+        Self { forecasts, stocks }
+    }
+
+    /// Archive une prévision servie pour un symbole ; un échec est journalisé sans être propagé
+    pub async fn record(&self, symbol: &str, forecast: &Forecast, history: &[PredictionPoint], config: &PredictionConfig) {
+        let Some(reference) = history.last().map(|p| p.close) else {
+            return;
+        };
+        let record = ForecastRecord::new(
+            symbol,
+            &forecast.method,
+            config.parameters.clone(),
+            config.interval.clone(),
+            config.confidence,
+            reference,
+            forecast.points.clone(),
+        );
+        if let Err(err) = self.forecasts.save_forecast(&record).await {
+            eprintln!("Erreur lors de l'archivage de la prévision {} : {:?}", record.id, err);
+        }
+    }
+
+    /// Confronte les prévisions en attente aux bougies stockées depuis leur création,
+    /// par lots de `PENDING_BATCH`
+    pub async fn evaluate_pending(&self) -> anyhow::Result<EvaluationReport> {
+        let mut report = EvaluationReport::default();
+        let mut after: Option<String> = None;
+        loop {
+            let batch = self.forecasts.pending_forecasts(after.as_deref(), PENDING_BATCH).await?;
+            let Some(last) = batch.last() else {
+                break;
+            };
+            after = Some(last.id.clone());
+            let exhausted = batch.len() < PENDING_BATCH;
+            self.evaluate_batch(batch, &mut report).await;
+            if exhausted {
+                break;
+            }
+        }
+        Ok(report)
+    }
+
+    async fn evaluate_batch(&self, batch: Vec<ForecastRecord>, report: &mut EvaluationReport) {
+        let mut by_symbol: BTreeMap<String, Vec<ForecastRecord>> = BTreeMap::new();
+        for record in batch {
+            by_symbol.entry(record.symbol.clone()).or_default().push(record);
+        }
+
+        for (symbol, records) in by_symbol {
+            report.pending += records.len();
+            // Un symbole en échec ne bloque pas l'évaluation des autres
+            let dto = match self.stocks.get_stock_dto(&symbol).await {
+                Ok(Some(dto)) => dto,
+                Ok(None) => {
+                    eprintln!("Évaluation des prévisions : symbole {} introuvable", symbol);
+                    continue;
+                }
+                Err(err) => {
+                    eprintln!("Évaluation des prévisions : lecture de {} impossible : {:?}", symbol, err);
+                    continue;
+                }
+            };
+            let candles = dto.sorted_points();
+
+            for mut record in records {
+                let realised = record.realise(&candles);
+                if realised == 0 {
+                    continue;
+                }
+                if let Err(err) = self.forecasts.save_forecast(&record).await {
+                    eprintln!("Erreur lors de l'enregistrement de la prévision {} : {:?}", record.id, err);
+                    continue;
+                }
+                report.updated += 1;
+                report.points += realised;
+                if record.complete {
+                    report.completed += 1;
+                }
+            }
+        }
+    }
+
+    /// Lance l'évaluation périodique en tâche de fond
+    pub fn spawn_evaluation_job(self: Arc<Self>, period: Duration) {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(period);
+            loop {
+                ticker.tick().await;
+                match self.evaluate_pending().await {
+                    Ok(report) if report.updated > 0 => println!(
+                        "Évaluation des prévisions : {} mises à jour, {} terminées, {} points réalisés",
+                        report.updated, report.completed, report.points
+                    ),
+                    Ok(_) => {}
+                    Err(err) => eprintln!("Erreur lors de l'évaluation des prévisions : {:?}", err),
+                }
+            }
+        });
+    }
+
+    /// Page de prévisions archivées du symbole, les plus récentes d'abord
+    pub async fn forecasts(&self, symbol: &str, method: Option<&str>, offset: usize, limit: usize) -> anyhow::Result<Vec<ForecastRecord>> {
+        self.forecasts.list_forecasts(symbol, method, offset, limit).await
+    }
+
+    /// Classement des méthodes sur leur erreur réalisée, éventuellement restreint à un symbole,
+    /// calculé sur les `LEADERBOARD_RECORDS` prévisions réalisées les plus récentes
+    pub async fn leaderboard(&self, symbol: Option<&str>) -> anyhow::Result<Leaderboard> {
+        let records = self.forecasts.realised_forecasts(symbol, LEADERBOARD_RECORDS).await?;
+        Ok(Leaderboard::from_records(&records))
+    }
+}
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use crate::domain::insight_profile::InsightProfile;
+
+#[async_trait]
+pub trait InsightProfileRepository: Send + Sync {
+    /// Profil d'un utilisateur, ou profil partagé si `owner` est absent
+    async fn get_profile(&self, name: &str, owner: Option<&str>) -> anyhow::Result<Option<InsightProfile>>;
+    /// Profils partagés et, le cas échéant, ceux de l'utilisateur
+    async fn list_profiles(&self, owner: Option<&str>) -> anyhow::Result<Vec<InsightProfile>>;
+    /// Remplace le profil de même nom et même propriétaire
+    async fn save_profile(&self, profile: &InsightProfile) -> anyhow::Result<()>;
+}
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use crate::domain::time_series::StockPoint;
+use crate::application::insight_profile_repository::InsightProfileRepository;
+use crate::domain::generic_stock_data_dto::GenericStockDataDTO;
+use crate::domain::insight_profile::{InsightProfile, DEFAULT_PROFILE};
+use crate::domain::stock_insights::StockInsights;
+use crate::domain::stock_insights_builder::StockInsightsBuilder;
+
+/// Calculs incrémentaux conservés ; au-delà, le moins récemment utilisé est abandonné
+pub const MAX_CACHED_BUILDERS: usize = 256;
+
+/// Calcul incrémental en cache, verrouillé séparément des autres
+struct CachedBuilder {
+    builder: Arc<Mutex<StockInsightsBuilder>>,
+    used: Instant,
+}
+
+/// Profils d'insights (fournis, partagés ou propres à un utilisateur) et calcul des insights
+pub struct InsightService {
+    repository: Arc<dyn InsightProfileRepository>,
+    /// Calculs incrémentaux par symbole et profil : seuls les nouveaux points sont intégrés
+    builders: Mutex<HashMap<String, CachedBuilder>>,
+}
+
+impl InsightService {
+    pub fn new(repository: Arc<dyn InsightProfileRepository>) -> Self {
+        Self { repository, builders: Mutex::new(HashMap::new()) }
+    }
+
+    /// Résout un profil par nom ("default" si absent) : profil de l'utilisateur,
+    /// puis profil partagé, puis profil fourni d'office
+    pub async fn resolve(&self, name: Option<&str>, user: Option<&str>) -> anyhow::Result<Option<InsightProfile>> {
+        let name = name.unwrap_or(DEFAULT_PROFILE);
+        if let Some(user) = user {
+            if let Some(profile) = self.repository.get_profile(name, Some(user)).await? {
+                return Ok(Some(profile));
+            }
+        }
+        if let Some(profile) = self.repository.get_profile(name, None).await? {
+            return Ok(Some(profile));
+        }
+        Ok(InsightProfile::builtin_named(name))
+    }
+
+    /// Profils visibles par l'utilisateur ; un profil enregistré masque le profil fourni de même nom
+    pub async fn profiles(&self, user: Option<&str>) -> anyhow::Result<Vec<InsightProfile>> {
+        let stored = self.repository.list_profiles(user).await?;
+        let mut profiles: Vec<InsightProfile> = InsightProfile::builtins()
+            .into_iter()
+            .filter(|builtin| !stored.iter().any(|p| p.name.eq_ignore_ascii_case(&builtin.name)))
+            .collect();
+        profiles.extend(stored);
+        Ok(profiles)
+    }
+
+    pub async fn save(&self, profile: &InsightProfile) -> anyhow::Result<()> {
+        self.repository.save_profile(profile).await
+    }
+
+    /// Calcul incrémental associé à la clé, créé au besoin ; le verrou du cache n'est tenu que
+    /// le temps de la recherche
+    fn builder(&self, key: String, profile: &InsightProfile) -> Arc<Mutex<StockInsightsBuilder>> {
+        let mut builders = self.builders.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !builders.contains_key(&key) && builders.len() >= MAX_CACHED_BUILDERS {
+            let oldest = builders.iter().min_by_key(|(_, cached)| cached.used).map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                builders.remove(&oldest);
+            }
+        }
+        let cached = builders.entry(key).or_insert_with(|| CachedBuilder {
+            builder: Arc::new(Mutex::new(StockInsightsBuilder::new(profile))),
+            used: Instant::now(),
+        });
+        cached.used = Instant::now();
+        cached.builder.clone()
+    }
+
+    /// Insights du stock selon le profil. Le calcul reprend là où la requête précédente s'était
+    /// arrêtée ; il repart de zéro si le profil a changé ou si l'historique a été révisé.
+    pub fn insights(&self, dto: &GenericStockDataDTO, profile: &InsightProfile) -> StockInsights {
+        let key = format!("{}:{}:{}", dto.symbol, profile.owner.as_deref().unwrap_or(""), profile.name);
+        let builder = self.builder(key, profile);
+        let mut builder = builder.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let points = || dto.historical_segments.iter().flat_map(|s| &s.data_points);
+        if builder.profile() != profile || !builder.continues(points()) {
+            *builder = StockInsightsBuilder::new(profile);
+        }
+        // Seuls les points postérieurs au dernier point intégré sont triés puis intégrés
+        let last = builder.last_timestamp();
+        let mut fresh: Vec<&StockPoint> = points().filter(|p| last.is_none_or(|last| p.timestamp > last)).collect();
+        fresh.sort_by_key(|p| p.timestamp);
+        builder.extend(fresh);
+        builder.build()
+    }
+}
@@ -1,5 +1,12 @@
 pub mod stock_manager;
 pub mod stock_repository;
+pub mod tuning_repository;
+pub mod forecast_repository;
+pub mod insight_profile_repository;
 pub mod stock_service;
 pub mod prediction_service;
+pub mod backtest_service;
+pub mod tuning_service;
+pub mod forecast_tracking_service;
+pub mod insight_service;
 pub mod predicators;
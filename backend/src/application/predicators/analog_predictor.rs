@@ -0,0 +1,210 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::application::predicators::parameter_spec::ParameterSpec;
+use crate::application::predicators::prediction_config::PredictionConfig;
+use crate::application::predicators::stock_predictor::StockPredictor;
+use crate::domain::forecast::Forecast;
+use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::statistics;
+
+const DEFAULT_WINDOW: usize = 20;
+const MIN_WINDOW: usize = 5;
+const DEFAULT_NEIGHBOURS: usize = 5;
+
+/// Distance entre deux fenêtres normalisées
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distance {
+    Euclidean,
+    /// Dynamic Time Warping, contraint à une bande de Sakoe-Chiba
+    Dtw,
+}
+
+/// Prévision par analogues : les k fenêtres passées les plus proches de la dernière fenêtre
+/// (après centrage-réduction) sont recherchées dans l'historique et les séries de référence,
+/// et la moyenne de leurs suites sert de prévision
+pub struct AnalogPredictor {
+    distance: Distance,
+}
+
+/// Fenêtre retenue comme analogue de la situation actuelle
+#[derive(Debug, Clone, Serialize)]
+pub struct AnalogMatch {
+    /// Symbole de la série de référence ; absent pour l'historique lui-même
+    pub source: Option<String>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub distance: f64,
+}
+
+struct Candidate<'a> {
+    source: Option<&'a str>,
+    points: &'a [PredictionPoint],
+    /// Indice de fin (exclu) de la fenêtre dans `points`
+    end: usize,
+    distance: f64,
+}
+
+impl AnalogPredictor {
+    pub fn new(distance: Distance) -> Self {
+        Self { distance }
+    }
+
+    fn distance(&self, a: &[f64], b: &[f64]) -> f64 {
+        match self.distance {
+            Distance::Euclidean => a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum::<f64>().sqrt(),
+            Distance::Dtw => dtw(a, b, (a.len() / 10).max(1)),
+        }
+    }
+}
+
+/// Fenêtre centrée-réduite, avec sa moyenne et son écart-type ; None si la fenêtre est plate
+fn normalise(values: &[f64]) -> Option<(Vec<f64>, f64, f64)> {
+    let mean = statistics::mean(values);
+    let std = statistics::std_dev(values);
+    if !(std > 0.0 && std.is_finite()) {
+        return None;
+    }
+    Some((values.iter().map(|v| (v - mean) / std).collect(), mean, std))
+}
+
+/// Distance DTW limitée aux alignements |i - j| ≤ band
+fn dtw(a: &[f64], b: &[f64], band: usize) -> f64 {
+    let (n, m) = (a.len(), b.len());
+    let mut cost = vec![vec![f64::INFINITY; m + 1]; n + 1];
+    cost[0][0] = 0.0;
+    for i in 1..=n {
+        for j in i.saturating_sub(band).max(1)..=(i + band).min(m) {
+            let step = cost[i - 1][j].min(cost[i][j - 1]).min(cost[i - 1][j - 1]);
+            cost[i][j] = (a[i - 1] - b[j - 1]).powi(2) + step;
+        }
+    }
+    cost[n][m].sqrt()
+}
+
+#[async_trait]
+impl StockPredictor for AnalogPredictor {
+    fn method_name(&self) -> &str {
+        match self.distance {
+            Distance::Euclidean => "ANALOG",
+            Distance::Dtw => "ANALOG_DTW",
+        }
+    }
+
+    fn description(&self) -> &str {
+        match self.distance {
+            Distance::Euclidean => "Analogues : moyenne des suites des k fenêtres passées les plus proches (distance euclidienne)",
+            Distance::Dtw => "Analogues : moyenne des suites des k fenêtres passées les plus proches (distance DTW)",
+        }
+    }
+
+    fn parameters(&self) -> Vec<ParameterSpec> {
+        vec![
+            ParameterSpec::integer("window", "Longueur des fenêtres comparées", Some(DEFAULT_WINDOW), MIN_WINDOW, 250),
+            ParameterSpec::integer("k", "Nombre d'analogues moyennés", Some(DEFAULT_NEIGHBOURS), 1, 50),
+        ]
+    }
+
+    /// La DTW, quadratique par fenêtre, est écartée de l'ensemble
+    fn suits_ensemble(&self) -> bool {
+        self.distance == Distance::Euclidean
+    }
+
+    /// Plus petite fenêtre, suivie d'un analogue qui ne la chevauche pas
+    fn min_history(&self) -> usize {
+        2 * MIN_WINDOW
+    }
+
+    /// La fenêtre courante, précédée d'au moins un analogue de l'historique ne la chevauchant pas
+    /// et suivi de `model_steps` points
+    fn required_history(&self, config: &PredictionConfig) -> usize {
+        let window = config.integer_parameter("window").unwrap_or(DEFAULT_WINDOW);
+        window + window.max(config.model_steps())
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
+        self.forecast(history, config).await.points
+    }
+
+    async fn forecast(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Forecast {
+        let window = config.integer_parameter("window").unwrap_or(DEFAULT_WINDOW);
+        let k = config.integer_parameter("k").unwrap_or(DEFAULT_NEIGHBOURS);
+        let steps = config.model_steps();
+        let empty = Forecast::new(self.method_name(), vec![]);
+
+        let closes: Vec<f64> = history.iter().map(|p| p.close).collect();
+        if closes.len() < window {
+            return empty;
+        }
+        let Some((query, query_mean, query_std)) = normalise(&closes[closes.len() - window..]) else {
+            return empty;
+        };
+
+        // Toute fenêtre suivie d'au moins `steps` points est candidate, sauf dans l'historique
+        // celles qui chevauchent la fenêtre courante
+        let query_start = closes.len() - window;
+        let sources = std::iter::once((None, history, query_start))
+            .chain(config.references.iter().map(|r| (Some(r.symbol.as_str()), r.points.as_slice(), r.points.len())));
+        let mut candidates = Vec::new();
+        for (source, points, last_end) in sources {
+            let values: Vec<f64> = points.iter().map(|p| p.close).collect();
+            for end in window..=values.len().saturating_sub(steps).min(last_end) {
+                if let Some((normalised, _, _)) = normalise(&values[end - window..end]) {
+                    let distance = self.distance(&query, &normalised);
+                    candidates.push(Candidate { source, points, end, distance });
+                }
+            }
+        }
+        candidates.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+
+        // Sélection gloutonne avec zone d'exclusion pour éviter des analogues quasi identiques
+        let exclusion = window / 2;
+        let mut selected: Vec<&Candidate> = Vec::with_capacity(k);
+        for candidate in &candidates {
+            if selected.len() == k {
+                break;
+            }
+            let overlaps = selected
+                .iter()
+                .any(|s| s.source == candidate.source && s.end.abs_diff(candidate.end) <= exclusion);
+            if !overlaps {
+                selected.push(candidate);
+            }
+        }
+        if selected.is_empty() {
+            return empty;
+        }
+
+        // Suites exprimées dans les unités réduites de leur fenêtre puis ramenées à l'échelle actuelle
+        let outcomes: Vec<Vec<f64>> = selected
+            .iter()
+            .map(|c| {
+                let values: Vec<f64> = c.points[c.end - window..c.end + steps].iter().map(|p| p.close).collect();
+                let mean = statistics::mean(&values[..window]);
+                let std = statistics::std_dev(&values[..window]);
+                values[window..].iter().map(|v| query_mean + query_std * (v - mean) / std).collect()
+            })
+            .collect();
+
+        let z = config.z_score();
+        let (values, half_widths): (Vec<f64>, Vec<f64>) = (0..steps)
+            .map(|h| {
+                let at_step: Vec<f64> = outcomes.iter().map(|o| o[h]).collect();
+                let spread = if at_step.len() > 1 { statistics::std_dev(&at_step) } else { 0.0 };
+                (statistics::mean(&at_step), z * spread)
+            })
+            .unzip();
+
+        let matches: Vec<AnalogMatch> = selected
+            .iter()
+            .map(|c| AnalogMatch {
+                source: c.source.map(str::to_string),
+                start: c.points[c.end - window].timestamp,
+                end: c.points[c.end - 1].timestamp,
+                distance: c.distance,
+            })
+            .collect();
+
+        Forecast::new(self.method_name(), config.build_points(history, &values, &half_widths)).with_detail("matches", matches)
+    }
+}
@@ -1,28 +1,132 @@
 use async_trait::async_trait;
-use chrono::{Utc, Duration};
+use crate::application::predicators::parameter_spec::ParameterSpec;
+use crate::application::predicators::prediction_config::PredictionConfig;
 use crate::application::predicators::stock_predictor::StockPredictor;
+use crate::domain::forecast::Forecast;
 use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::statistics;
 
 pub struct ArPredictor;
 
+/// Modèle autorégressif AR(p) centré : x_t - μ = Σ φ_i (x_{t-i} - μ) + ε_t
+#[derive(Debug, Clone)]
+pub struct ArModel {
+    pub mean: f64,
+    pub coefficients: Vec<f64>,
+    pub noise_variance: f64,
+}
+
+impl ArModel {
+    /// Ajuste les coefficients par Yule-Walker et choisit l'ordre p minimisant l'AIC
+    pub fn fit(series: &[f64], max_order: usize) -> Self {
+        let n = series.len();
+        let mean = statistics::mean(series);
+        let max_order = max_order.min(n.saturating_sub(1));
+        let acov = statistics::autocovariances(series, max_order);
+
+        let (coefficients, noise_variance) = statistics::levinson_durbin(&acov, max_order)
+            .into_iter()
+            .enumerate()
+            .min_by(|(p_a, (_, s_a)), (p_b, (_, s_b))| {
+                let aic_a = statistics::aic(*s_a, n, p_a + 1);
+                let aic_b = statistics::aic(*s_b, n, p_b + 1);
+                aic_a.partial_cmp(&aic_b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(_, fit)| fit)
+            .unwrap_or((Vec::new(), 0.0));
+
+        Self { mean, coefficients, noise_variance }
+    }
+
+    /// Ajuste un AR d'ordre imposé, borné par la taille de la série
+    pub fn fit_order(series: &[f64], order: usize) -> Self {
+        let mean = statistics::mean(series);
+        let order = order.min(series.len().saturating_sub(1));
+        let acov = statistics::autocovariances(series, order);
+
+        let (coefficients, noise_variance) = statistics::levinson_durbin(&acov, order)
+            .pop()
+            .unwrap_or((Vec::new(), 0.0));
+
+        Self { mean, coefficients, noise_variance }
+    }
+
+    pub fn order(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// Déroule la récursion sur `horizon` pas à partir de la fin de la série
+    pub fn forecast(&self, series: &[f64], horizon: usize) -> Vec<f64> {
+        let mut centered: Vec<f64> = series.iter().map(|v| v - self.mean).collect();
+        let mut out = Vec::with_capacity(horizon);
+
+        for _ in 0..horizon {
+            let len = centered.len();
+            let next: f64 = self.coefficients
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i < len)
+                .map(|(i, phi)| phi * centered[len - 1 - i])
+                .sum();
+            centered.push(next);
+            out.push(next + self.mean);
+        }
+
+        out
+    }
+
+    /// Écart-type de l'erreur de prévision pour h = 1..horizon (poids ψ du modèle)
+    pub fn forecast_std_devs(&self, horizon: usize) -> Vec<f64> {
+        let psi = statistics::psi_weights(&self.coefficients, &[], horizon);
+        statistics::forecast_std_devs(&psi, self.noise_variance)
+    }
+}
+
+/// Ordre maximal testé : règle usuelle 10·log10(n), bornée par la taille de l'historique
+fn max_order_for(n: usize) -> usize {
+    let rule = (10.0 * (n as f64).log10()).floor() as usize;
+    rule.min(n / 2)
+}
+
 #[async_trait]
 impl StockPredictor for ArPredictor {
     fn method_name(&self) -> &str {
         "AR"
     }
 
-    async fn predict(&self, history: &[PredictionPoint]) -> Vec<PredictionPoint> {
+    fn description(&self) -> &str {
+        "Autorégressif AR(p) par Yule-Walker, ordre choisi par AIC"
+    }
+
+    fn parameters(&self) -> Vec<ParameterSpec> {
+        vec![ParameterSpec::integer("order", "Ordre p imposé (choisi par AIC par défaut)", None, 1, 50)]
+    }
+
+    fn min_history(&self) -> usize {
+        2
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
+        self.forecast(history, config).await.points
+    }
+
+    async fn forecast(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Forecast {
         if history.is_empty() {
-            return vec![];
+            return Forecast::new(self.method_name(), vec![]);
         }
 
-        let last = history.last().unwrap().close;
+        let closes: Vec<f64> = history.iter().map(|p| p.close).collect();
+        let model = match config.integer_parameter("order") {
+            Some(order) => ArModel::fit_order(&closes, order),
+            None => ArModel::fit(&closes, max_order_for(closes.len())),
+        };
+        let steps = config.model_steps();
+        let z = config.z_score();
+        let forecast = model.forecast(&closes, steps);
+        let half_widths: Vec<f64> = model.forecast_std_devs(steps).iter().map(|s| z * s).collect();
 
-        (1..=31)
-            .map(|i| {
-                let ts = Utc::now() + Duration::days(i);
-                PredictionPoint::new(ts, last, last, last)
-            })
-            .collect()
+        Forecast::new(self.method_name(), config.build_points(history, &forecast, &half_widths))
+            .with_detail("order", model.order())
+            .with_detail("noise_variance", model.noise_variance)
     }
 }
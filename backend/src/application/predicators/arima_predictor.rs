@@ -0,0 +1,242 @@
+use async_trait::async_trait;
+use crate::application::predicators::parameter_spec::ParameterSpec;
+use crate::application::predicators::prediction_config::PredictionConfig;
+use crate::application::predicators::stock_predictor::StockPredictor;
+use crate::domain::forecast::Forecast;
+use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::statistics;
+
+pub struct ArimaPredictor;
+
+const MAX_P: usize = 3;
+const MAX_Q: usize = 3;
+const MAX_D: usize = 2;
+
+/// Modèle ARIMA(p,d,q) : la série est différenciée d fois jusqu'à stationnarité (test ADF),
+/// la partie ARMA est estimée par Hannan-Rissanen et l'ordre (p,q) choisi par AIC.
+#[derive(Debug, Clone)]
+pub struct ArimaModel {
+    pub d: usize,
+    pub mean: f64,
+    pub ar: Vec<f64>,
+    pub ma: Vec<f64>,
+    pub noise_variance: f64,
+    /// Série différenciée d fois, centrée
+    centered: Vec<f64>,
+    /// Résidus in-sample de la partie ARMA
+    residuals: Vec<f64>,
+    /// Dernière valeur de chaque niveau de différenciation (niveau 0 = prix)
+    anchors: Vec<f64>,
+}
+
+impl ArimaModel {
+    /// Recherche l'ordre (p,q) jusqu'à (max_p, max_q) après au plus max_d différenciations
+    pub fn fit(series: &[f64], max_p: usize, max_q: usize, max_d: usize) -> Option<Self> {
+        if series.len() < 3 {
+            return None;
+        }
+
+        let mut work = series.to_vec();
+        let mut anchors = Vec::new();
+        while anchors.len() < max_d && work.len() > 3 && !statistics::is_stationary(&work) {
+            anchors.push(*work.last()?);
+            work = statistics::difference(&work);
+        }
+
+        let mean = statistics::mean(&work);
+        let centered: Vec<f64> = work.iter().map(|v| v - mean).collect();
+
+        let mut best: Option<(f64, Self)> = None;
+        for p in 0..=max_p {
+            for q in 0..=max_q {
+                let Some((ar, ma)) = fit_arma(&centered, p, q) else { continue };
+                let residuals = arma_residuals(&centered, &ar, &ma);
+                let start = p.max(q);
+                let count = centered.len().saturating_sub(start);
+                if count == 0 {
+                    continue;
+                }
+                let sigma2 = residuals[start..].iter().map(|e| e * e).sum::<f64>() / count as f64;
+                if !sigma2.is_finite() {
+                    continue;
+                }
+
+                let aic = statistics::aic(sigma2, count, p + q + 1);
+                if best.as_ref().is_none_or(|(best_aic, _)| aic < *best_aic) {
+                    let model = Self {
+                        d: anchors.len(),
+                        mean,
+                        ar,
+                        ma,
+                        noise_variance: sigma2,
+                        centered: centered.clone(),
+                        residuals,
+                        anchors: anchors.clone(),
+                    };
+                    best = Some((aic, model));
+                }
+            }
+        }
+
+        best.map(|(_, model)| model)
+    }
+
+    /// Prévision ARMA sur la série différenciée, puis réintégration jusqu'au niveau des prix
+    pub fn forecast(&self, horizon: usize) -> Vec<f64> {
+        let mut x = self.centered.clone();
+        let mut e = self.residuals.clone();
+        let mut out = Vec::with_capacity(horizon);
+
+        for _ in 0..horizon {
+            let next = arma_step(&x, &e, &self.ar, &self.ma);
+            x.push(next);
+            e.push(0.0);
+            out.push(next + self.mean);
+        }
+
+        for anchor in self.anchors.iter().rev() {
+            let mut level = *anchor;
+            for value in out.iter_mut() {
+                level += *value;
+                *value = level;
+            }
+        }
+
+        out
+    }
+
+    /// Écart-type de l'erreur de prévision au niveau des prix pour h = 1..horizon :
+    /// les poids ψ de l'ARMA sont cumulés d fois pour tenir compte de l'intégration.
+    pub fn forecast_std_devs(&self, horizon: usize) -> Vec<f64> {
+        let mut psi = statistics::psi_weights(&self.ar, &self.ma, horizon);
+        for _ in 0..self.d {
+            let mut acc = 0.0;
+            for w in psi.iter_mut() {
+                acc += *w;
+                *w = acc;
+            }
+        }
+        statistics::forecast_std_devs(&psi, self.noise_variance)
+    }
+}
+
+/// Valeur attendue au prochain pas : Σ φ_i x_{t-i} + Σ θ_j ε_{t-j}
+fn arma_step(x: &[f64], e: &[f64], ar: &[f64], ma: &[f64]) -> f64 {
+    let ar_part: f64 = ar
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i < x.len())
+        .map(|(i, phi)| phi * x[x.len() - 1 - i])
+        .sum();
+    let ma_part: f64 = ma
+        .iter()
+        .enumerate()
+        .filter(|(j, _)| *j < e.len())
+        .map(|(j, theta)| theta * e[e.len() - 1 - j])
+        .sum();
+    ar_part + ma_part
+}
+
+/// Résidus conditionnels (CSS) du modèle ARMA, initialisés à zéro
+fn arma_residuals(x: &[f64], ar: &[f64], ma: &[f64]) -> Vec<f64> {
+    let start = ar.len().max(ma.len());
+    let mut residuals = vec![0.0; x.len()];
+    for t in start..x.len() {
+        residuals[t] = x[t] - arma_step(&x[..t], &residuals[..t], ar, ma);
+    }
+    residuals
+}
+
+/// Estimation de Hannan-Rissanen : un AR long fournit des innovations approchées,
+/// puis x_t est régressé sur ses p retards et les q retards de ces innovations.
+fn fit_arma(x: &[f64], p: usize, q: usize) -> Option<(Vec<f64>, Vec<f64>)> {
+    if p == 0 && q == 0 {
+        return Some((Vec::new(), Vec::new()));
+    }
+
+    let n = x.len();
+    let long_order = if q == 0 { 0 } else { (p + q).max((n as f64).ln().ceil() as usize) };
+    let start = long_order + p.max(q);
+    if n <= start + p + q {
+        return None;
+    }
+
+    let innovations = if q == 0 {
+        vec![0.0; n]
+    } else {
+        let acov = statistics::autocovariances(x, long_order);
+        let (phi, _) = statistics::levinson_durbin(&acov, long_order).pop()?;
+        if phi.len() < long_order {
+            return None;
+        }
+        arma_residuals(x, &phi, &[])
+    };
+
+    let mut design = Vec::new();
+    let mut target = Vec::new();
+    for t in start..n {
+        let mut row: Vec<f64> = (1..=p).map(|i| x[t - i]).collect();
+        row.extend((1..=q).map(|j| innovations[t - j]));
+        design.push(row);
+        target.push(x[t]);
+    }
+
+    let fit = statistics::ols(&design, &target)?;
+    let (ar, ma) = fit.coefficients.split_at(p);
+
+    // Condition suffisante de stationnarité / inversibilité : Σ|coef| < 1
+    if ar.iter().map(|c| c.abs()).sum::<f64>() >= 1.0 || ma.iter().map(|c| c.abs()).sum::<f64>() >= 1.0 {
+        return None;
+    }
+
+    Some((ar.to_vec(), ma.to_vec()))
+}
+
+#[async_trait]
+impl StockPredictor for ArimaPredictor {
+    fn method_name(&self) -> &str {
+        "ARIMA"
+    }
+
+    fn description(&self) -> &str {
+        "ARIMA(p,d,q) : différenciation par test ADF, ordre ARMA choisi par AIC"
+    }
+
+    fn parameters(&self) -> Vec<ParameterSpec> {
+        vec![
+            ParameterSpec::integer("max_p", "Ordre autorégressif maximal", Some(MAX_P), 0, 5),
+            ParameterSpec::integer("max_q", "Ordre moyenne mobile maximal", Some(MAX_Q), 0, 5),
+            ParameterSpec::integer("max_d", "Nombre maximal de différenciations", Some(MAX_D), 0, 2),
+        ]
+    }
+
+    fn min_history(&self) -> usize {
+        3
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
+        self.forecast(history, config).await.points
+    }
+
+    async fn forecast(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Forecast {
+        let closes: Vec<f64> = history.iter().map(|p| p.close).collect();
+        let model = match ArimaModel::fit(
+            &closes,
+            config.integer_parameter("max_p").unwrap_or(MAX_P),
+            config.integer_parameter("max_q").unwrap_or(MAX_Q),
+            config.integer_parameter("max_d").unwrap_or(MAX_D),
+        ) {
+            Some(model) => model,
+            None => return Forecast::new(self.method_name(), vec![]),
+        };
+
+        let steps = config.model_steps();
+        let z = config.z_score();
+        let forecast = model.forecast(steps);
+        let half_widths: Vec<f64> = model.forecast_std_devs(steps).iter().map(|s| z * s).collect();
+
+        Forecast::new(self.method_name(), config.build_points(history, &forecast, &half_widths))
+            .with_detail("order", [model.ar.len(), model.d, model.ma.len()])
+            .with_detail("noise_variance", model.noise_variance)
+    }
+}
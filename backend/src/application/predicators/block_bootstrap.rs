@@ -0,0 +1,173 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use rand::Rng;
+use serde::Serialize;
+use crate::application::predicators::parameter_spec::ParameterSpec;
+use crate::application::predicators::prediction_config::PredictionConfig;
+use crate::application::predicators::stock_predictor::StockPredictor;
+use crate::domain::forecast::Forecast;
+use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::statistics;
+
+/// Nombre minimal de résidus pour rééchantillonner
+const MIN_RESIDUALS: usize = 10;
+/// Nombre maximal d'origines de prévision à un pas utilisées pour les résidus
+const MAX_CALIBRATION: usize = 100;
+
+/// Résumé du bootstrap, renvoyé dans les détails de la prévision
+#[derive(Debug, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum BootstrapSummary {
+    Applied { resamples: usize, block_length: usize, residuals: usize },
+    /// Bandes du modèle conservées
+    Skipped { reason: String, residuals: usize, required: usize },
+}
+
+/// Bandes upper/lower obtenues par bootstrap par blocs mobiles, quel que soit le prédicteur.
+///
+/// Les résidus sont les erreurs des prévisions à un pas du prédicteur sur les derniers points
+/// de l'historique. Chaque rééchantillonnage concatène des blocs contigus de résidus tirés au
+/// hasard (ce qui préserve leur autocorrélation) ; l'erreur au pas h est la somme des h premiers
+/// résidus tirés, et ses quantiles empiriques encadrent la prévision ponctuelle du prédicteur.
+pub struct BlockBootstrapPredictor {
+    inner: Arc<dyn StockPredictor>,
+}
+
+impl BlockBootstrapPredictor {
+    pub fn new(inner: Arc<dyn StockPredictor>) -> Self {
+        Self { inner }
+    }
+
+    /// Erreurs des prévisions à un pas de `inner`, de la plus ancienne à la plus récente
+    async fn residuals(inner: &dyn StockPredictor, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<f64> {
+        let mut one_step = config.clone();
+        one_step.horizon = 1;
+        one_step.step = 1;
+
+        let first = history
+            .len()
+            .saturating_sub(MAX_CALIBRATION)
+            .max(inner.required_history(&one_step));
+
+        let mut residuals = Vec::with_capacity(history.len().saturating_sub(first));
+        for origin in first..history.len() {
+            let predicted = inner.predict(&history[..origin], &one_step).await;
+            if let Some(p) = predicted.first().filter(|p| p.close.is_finite()) {
+                residuals.push(history[origin].close - p.close);
+            }
+        }
+        residuals
+    }
+}
+
+/// Longueur de bloc usuelle n^(1/3)
+fn block_length(residuals: usize) -> usize {
+    ((residuals as f64).cbrt().round() as usize).max(1)
+}
+
+#[async_trait]
+impl StockPredictor for BlockBootstrapPredictor {
+    fn method_name(&self) -> &str {
+        self.inner.method_name()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn parameters(&self) -> Vec<ParameterSpec> {
+        self.inner.parameters()
+    }
+
+    fn min_history(&self) -> usize {
+        self.inner.min_history() + MIN_RESIDUALS
+    }
+
+    fn required_history(&self, config: &PredictionConfig) -> usize {
+        self.inner.required_history(config) + MIN_RESIDUALS
+    }
+
+    /// Un ajustement, plus une prévision à un pas par origine de calibrage
+    fn fit_cost(&self, config: &PredictionConfig) -> usize {
+        self.inner.fit_cost(config).saturating_mul(MAX_CALIBRATION + 1)
+    }
+
+    fn models_returns(&self) -> bool {
+        self.inner.models_returns()
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
+        self.forecast(history, config).await.points
+    }
+
+    async fn forecast(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Forecast {
+        let mut unit = config.clone();
+        unit.horizon = config.model_steps();
+        unit.step = 1;
+        // Les choix faits sur l'historique complet (poids d'un ensemble…) valent pour les réajustements
+        let frozen = self.inner.frozen(history, &unit).await;
+        let inner = frozen.as_ref().unwrap_or(&self.inner).as_ref();
+        let forecast = inner.forecast(history, &unit).await;
+
+        let residuals = Self::residuals(inner, history, config).await;
+        if residuals.len() < MIN_RESIDUALS {
+            let skipped = BootstrapSummary::Skipped {
+                reason: "Trop peu de résidus de calibrage, bandes du modèle conservées".to_string(),
+                residuals: residuals.len(),
+                required: MIN_RESIDUALS,
+            };
+            return Forecast {
+                points: config.build_banded_points(
+                    history,
+                    &forecast.points.iter().map(|p| p.close).collect::<Vec<_>>(),
+                    &forecast.points.iter().map(|p| p.upper).collect::<Vec<_>>(),
+                    &forecast.points.iter().map(|p| p.lower).collect::<Vec<_>>(),
+                ),
+                ..forecast
+            }
+            .with_detail("bootstrap", skipped);
+        }
+
+        let steps = forecast.points.len();
+        let block = block_length(residuals.len()).min(residuals.len());
+        let mut rng = rand::rng();
+
+        // errors[s][b] : erreur cumulée au pas s + 1 du rééchantillonnage b
+        let mut errors = vec![Vec::with_capacity(config.resamples); steps];
+        for _ in 0..config.resamples {
+            let mut cumulative = 0.0;
+            let mut s = 0;
+            while s < steps {
+                let start = rng.random_range(0..=residuals.len() - block);
+                for residual in &residuals[start..start + block] {
+                    if s == steps {
+                        break;
+                    }
+                    cumulative += residual;
+                    errors[s].push(cumulative);
+                    s += 1;
+                }
+            }
+        }
+
+        let tail = (1.0 - config.confidence) / 2.0;
+        let values: Vec<f64> = forecast.points.iter().map(|p| p.close).collect();
+        let (mut upper, mut lower) = (Vec::with_capacity(steps), Vec::with_capacity(steps));
+        for (value, mut step_errors) in values.iter().zip(errors) {
+            step_errors.sort_by(f64::total_cmp);
+            upper.push(value + statistics::quantile_sorted(&step_errors, 1.0 - tail));
+            lower.push(value + statistics::quantile_sorted(&step_errors, tail));
+        }
+
+        let summary = BootstrapSummary::Applied {
+            resamples: config.resamples,
+            block_length: block,
+            residuals: residuals.len(),
+        };
+        Forecast {
+            points: config.build_banded_points(history, &values, &upper, &lower),
+            ..forecast
+        }
+        .with_detail("bootstrap", summary)
+    }
+}
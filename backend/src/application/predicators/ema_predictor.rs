@@ -1,7 +1,9 @@
 use async_trait::async_trait;
-use chrono::{Utc, Duration};
+use crate::application::predicators::parameter_spec::ParameterSpec;
+use crate::application::predicators::prediction_config::PredictionConfig;
 use crate::application::predicators::stock_predictor::StockPredictor;
 use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::statistics;
 
 pub struct EmaPredictor;
 
@@ -11,23 +13,36 @@ impl StockPredictor for EmaPredictor {
         "EMA"
     }
 
-    async fn predict(&self, history: &[PredictionPoint]) -> Vec<PredictionPoint> {
+    fn description(&self) -> &str {
+        "Moyenne mobile exponentielle de l'historique"
+    }
+
+    fn parameters(&self) -> Vec<ParameterSpec> {
+        vec![ParameterSpec::float("alpha", "Facteur de lissage (2/(n+1) par défaut)", None, 0.01, 1.0)]
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
         if history.is_empty() {
             return vec![];
         }
 
-        let alpha = 2.0 / (history.len() as f64 + 1.0);
+        let alpha = config.parameter("alpha").unwrap_or(2.0 / (history.len() as f64 + 1.0));
         let mut ema = history[0].close;
+        let mut errors = Vec::with_capacity(history.len());
 
         for p in history.iter().skip(1) {
+            errors.push(p.close - ema);
             ema = alpha * p.close + (1.0 - alpha) * ema;
         }
 
-        (1..=31)
-            .map(|i| {
-                let ts = Utc::now() + Duration::days(i);
-                PredictionPoint::new(ts, ema, ema, ema)
-            })
-            .collect()
+        // Les erreurs de prévision in-sample à un pas servent d'échelle à la bande
+        let sigma = statistics::rms(&errors);
+        let z = config.z_score();
+
+        let steps = config.model_steps();
+        let values = vec![ema; steps];
+        let half_widths: Vec<f64> = (1..=steps).map(|h| z * sigma * (h as f64).sqrt()).collect();
+
+        config.build_points(history, &values, &half_widths)
     }
 }
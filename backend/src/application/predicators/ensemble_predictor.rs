@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::application::backtest_service::{BacktestService, BacktestSettings, WindowMode};
+use crate::application::predicators::prediction_config::PredictionConfig;
+use crate::application::predicators::stock_predictor::StockPredictor;
+use crate::domain::forecast::Forecast;
+use crate::domain::prediction_point::PredictionPoint;
+
+/// Taille minimale de la fenêtre d'apprentissage pour la validation des membres
+const MIN_TRAIN_SIZE: usize = 10;
+/// Nombre maximal d'origines rejouées sur la fin de l'historique
+const MAX_VALIDATION_POINTS: usize = 20;
+
+/// Combinaison des autres prédicteurs, pondérés par l'inverse de leur erreur quadratique
+/// moyenne lors d'un backtest walk-forward sur la fin de l'historique
+pub struct EnsemblePredictor {
+    members: Vec<Arc<dyn StockPredictor>>,
+    /// Validation figée sur l'historique complet, réutilisée par les réajustements
+    fixed: Option<Validation>,
+}
+
+/// Scores inverse-MSE (non normalisés) des membres et MSE de validation
+#[derive(Debug, Clone)]
+struct Validation {
+    scores: Vec<f64>,
+    errors: Vec<Option<f64>>,
+}
+
+impl Validation {
+    /// Scores égaux, sans erreur connue
+    fn equal(members: usize) -> Self {
+        Self { scores: vec![1.0; members], errors: vec![None; members] }
+    }
+}
+
+impl EnsemblePredictor {
+    /// Seuls les membres déterministes et peu coûteux (`suits_ensemble`) sont retenus
+    pub fn new(candidates: Vec<Arc<dyn StockPredictor>>) -> Self {
+        let members = candidates.into_iter().filter(|m| m.suits_ensemble()).collect();
+        Self { members, fixed: None }
+    }
+
+    /// Validation des membres, figée ou calculée sur les threads bloquants plutôt que sur l'exécuteur
+    async fn validation(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Validation {
+        if let Some(fixed) = &self.fixed {
+            return fixed.clone();
+        }
+        let (members, history, config) = (self.members.clone(), history.to_vec(), config.clone());
+        let task = tokio::task::spawn_blocking(move || futures::executor::block_on(Self::validate(&members, &history, &config)));
+        match task.await {
+            Ok(validation) => validation,
+            Err(err) => {
+                eprintln!("Validation de l'ensemble interrompue : {:?}", err);
+                Validation::equal(self.members.len())
+            }
+        }
+    }
+
+    /// Backtest de chaque membre sur la fin de l'historique ; scores égaux si l'historique
+    /// est trop court pour valider
+    async fn validate(members: &[Arc<dyn StockPredictor>], history: &[PredictionPoint], config: &PredictionConfig) -> Validation {
+        let validation = (history.len() / 5).min(MAX_VALIDATION_POINTS);
+        let train_size = history.len().saturating_sub(validation);
+        if validation == 0 || train_size < MIN_TRAIN_SIZE {
+            return Validation::equal(members.len());
+        }
+
+        let settings = BacktestSettings {
+            window: WindowMode::Expanding,
+            train_size,
+            horizon: config.model_steps().min(validation),
+            stride: 1,
+            confidence: config.confidence,
+            interval: config.interval.clone(),
+            parameters: BTreeMap::new(),
+        };
+
+        let mut errors = Vec::with_capacity(members.len());
+        for member in members {
+            let scorecard = BacktestService::evaluate(member.as_ref(), history, &settings).await;
+            errors.push(scorecard.metrics.rmse.filter(|r| r.is_finite()).map(|rmse| rmse * rmse));
+        }
+
+        // Un membre sans erreur l'emporte entièrement (partagé s'il y en a plusieurs)
+        let scores = if errors.contains(&Some(0.0)) {
+            errors.iter().map(|e| if *e == Some(0.0) { 1.0 } else { 0.0 }).collect()
+        } else {
+            errors.iter().map(|e| e.map(|mse| 1.0 / mse).unwrap_or(0.0)).collect()
+        };
+
+        Validation { scores, errors }
+    }
+}
+
+#[async_trait]
+impl StockPredictor for EnsemblePredictor {
+    fn method_name(&self) -> &str {
+        "ENSEMBLE"
+    }
+
+    fn description(&self) -> &str {
+        "Combinaison des autres méthodes pondérée par l'inverse de leur erreur de validation"
+    }
+
+    /// Il suffit qu'un membre puisse prédire
+    fn min_history(&self) -> usize {
+        self.members.iter().map(|m| m.min_history()).min().unwrap_or(1)
+    }
+
+    /// Chaque membre est validé sur au plus `MAX_VALIDATION_POINTS` origines puis ajusté
+    fn fit_cost(&self, config: &PredictionConfig) -> usize {
+        self.members
+            .iter()
+            .map(|m| m.fit_cost(config))
+            .fold(0, usize::saturating_add)
+            .saturating_mul(MAX_VALIDATION_POINTS + 1)
+    }
+
+    async fn frozen(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Option<Arc<dyn StockPredictor>> {
+        let fixed = self.validation(history, config).await;
+        Some(Arc::new(EnsemblePredictor { members: self.members.clone(), fixed: Some(fixed) }))
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
+        self.forecast(history, config).await.points
+    }
+
+    async fn forecast(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Forecast {
+        let Validation { scores: mut weights, errors } = self.validation(history, config).await;
+
+        let mut forecasts = Vec::with_capacity(self.members.len());
+        for member in &self.members {
+            forecasts.push(member.predict(history, config).await);
+        }
+
+        // Les membres sans prévision complète sont écartés et les poids renormalisés
+        let horizon = forecasts.iter().map(|f| f.len()).max().unwrap_or(0);
+        for (weight, forecast) in weights.iter_mut().zip(&forecasts) {
+            if forecast.len() < horizon {
+                *weight = 0.0;
+            }
+        }
+        if horizon == 0 {
+            return Forecast::new(self.method_name(), vec![]);
+        }
+        if weights.iter().sum::<f64>() <= 0.0 {
+            for (weight, forecast) in weights.iter_mut().zip(&forecasts) {
+                *weight = if forecast.len() == horizon { 1.0 } else { 0.0 };
+            }
+        }
+        let total: f64 = weights.iter().sum();
+        weights.iter_mut().for_each(|w| *w /= total);
+
+        // Mélange des lois prédictives : variance = Σ w_i (σ_i² + (μ_i - μ)²),
+        // σ_i étant déduit de la demi-largeur de bande de chaque membre
+        let z = config.z_score();
+        let points = (0..horizon)
+            .map(|k| {
+                let members: Vec<(&PredictionPoint, f64)> = forecasts
+                    .iter()
+                    .zip(&weights)
+                    .filter(|(_, w)| **w > 0.0)
+                    .map(|(f, w)| (&f[k], *w))
+                    .collect();
+
+                let mean: f64 = members.iter().map(|(p, w)| w * p.close).sum();
+                let variance: f64 = members
+                    .iter()
+                    .map(|(p, w)| {
+                        let sigma = (p.upper - p.lower) / (2.0 * z);
+                        w * (sigma * sigma + (p.close - mean).powi(2))
+                    })
+                    .sum();
+                let half_width = z * variance.sqrt();
+                PredictionPoint::new(members[0].0.timestamp, mean, mean + half_width, mean - half_width)
+            })
+            .collect();
+
+        let names = self.members.iter().map(|m| m.method_name().to_string());
+        let weight_map: BTreeMap<String, f64> = names.clone().zip(weights.iter().copied()).collect();
+        let error_map: BTreeMap<String, Option<f64>> = names.zip(errors).collect();
+
+        Forecast::new(self.method_name(), points)
+            .with_detail("weights", weight_map)
+            .with_detail("validation_mse", error_map)
+    }
+}
@@ -0,0 +1,223 @@
+use async_trait::async_trait;
+use crate::application::predicators::parameter_spec::ParameterSpec;
+use crate::application::predicators::prediction_config::PredictionConfig;
+use crate::application::predicators::stock_predictor::StockPredictor;
+use crate::domain::forecast::Forecast;
+use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::statistics;
+use crate::domain::time_series::TimeInterval;
+
+/// Lissage exponentiel double de Holt (niveau + tendance)
+pub struct HoltPredictor;
+
+/// Lissage de Holt-Winters additif (niveau + tendance + saisonnalité)
+pub struct HoltWintersPredictor;
+
+/// Modèle de lissage exponentiel additif ; sans saisonnalité si `season_length` vaut 0
+#[derive(Debug, Clone)]
+pub struct ExponentialSmoothing {
+    pub alpha: f64,
+    pub beta: f64,
+    pub gamma: f64,
+    pub season_length: usize,
+    pub level: f64,
+    pub trend: f64,
+    /// Dernière saison de coefficients saisonniers, dans l'ordre chronologique
+    pub seasonals: Vec<f64>,
+    pub noise_variance: f64,
+}
+
+impl ExponentialSmoothing {
+    /// Optimise α, β (et γ) en minimisant la somme des carrés des erreurs de prévision à un pas
+    pub fn fit(series: &[f64], season_length: usize) -> Option<Self> {
+        let seasonal = season_length > 1;
+        let min_len = if seasonal { 2 * season_length } else { 3 };
+        if series.len() < min_len {
+            return None;
+        }
+        let season_length = if seasonal { season_length } else { 0 };
+
+        let start: Vec<f64> = if seasonal {
+            vec![statistics::logit(0.3), statistics::logit(0.1), statistics::logit(0.1)]
+        } else {
+            vec![statistics::logit(0.3), statistics::logit(0.1)]
+        };
+        let decode = |raw: &[f64]| -> (f64, f64, f64) {
+            let alpha = statistics::logistic(raw[0]);
+            let beta = statistics::logistic(raw[1]);
+            let gamma = raw.get(2).map(|g| statistics::logistic(*g)).unwrap_or(0.0);
+            (alpha, beta, gamma)
+        };
+
+        let best = statistics::nelder_mead(
+            |raw| {
+                let (alpha, beta, gamma) = decode(raw);
+                Self::smooth(series, alpha, beta, gamma, season_length).noise_variance
+            },
+            &start,
+            1.0,
+            300,
+        );
+
+        let (alpha, beta, gamma) = decode(&best);
+        Some(Self::smooth(series, alpha, beta, gamma, season_length))
+    }
+
+    /// Applique les récursions de lissage sur toute la série
+    fn smooth(series: &[f64], alpha: f64, beta: f64, gamma: f64, season_length: usize) -> Self {
+        let m = season_length;
+        let (mut level, mut trend, mut seasonals, start) = if m > 0 {
+            let first = statistics::mean(&series[..m]);
+            let second = statistics::mean(&series[m..2 * m]);
+            let seasonals: Vec<f64> = series[..m].iter().map(|y| y - first).collect();
+            (first, (second - first) / m as f64, seasonals, m)
+        } else {
+            (series[0], series[1] - series[0], Vec::new(), 1)
+        };
+
+        let mut sq_errors = 0.0;
+        for (t, y) in series.iter().enumerate().skip(start) {
+            let season = if m > 0 { seasonals[t % m] } else { 0.0 };
+            let error = y - (level + trend + season);
+            sq_errors += error * error;
+
+            let previous_level = level;
+            level = alpha * (y - season) + (1.0 - alpha) * (level + trend);
+            trend = beta * (level - previous_level) + (1.0 - beta) * trend;
+            if m > 0 {
+                seasonals[t % m] = gamma * (y - level) + (1.0 - gamma) * season;
+            }
+        }
+
+        // Remet les coefficients saisonniers dans l'ordre : le premier correspond au prochain pas
+        if m > 0 {
+            seasonals.rotate_left(series.len() % m);
+        }
+
+        let noise_variance = sq_errors / (series.len() - start) as f64;
+        Self { alpha, beta, gamma, season_length: m, level, trend, seasonals, noise_variance }
+    }
+
+    pub fn forecast(&self, horizon: usize) -> Vec<f64> {
+        (1..=horizon)
+            .map(|h| {
+                let season = if self.season_length > 0 { self.seasonals[(h - 1) % self.season_length] } else { 0.0 };
+                self.level + h as f64 * self.trend + season
+            })
+            .collect()
+    }
+
+    /// Écart-type de l'erreur à h pas : σ·sqrt(1 + Σ_{j<h} c_j²)
+    /// avec c_j = α(1 + jβ) + γ(1 - α)·1{j multiple de m} (forme espace d'état ETS additive)
+    pub fn forecast_std_devs(&self, horizon: usize) -> Vec<f64> {
+        let mut acc = 1.0;
+        (1..=horizon)
+            .map(|h| {
+                if h > 1 {
+                    let j = h - 1;
+                    let seasonal = self.season_length > 0 && j % self.season_length == 0;
+                    let c = self.alpha * (1.0 + j as f64 * self.beta)
+                        + if seasonal { self.gamma * (1.0 - self.alpha) } else { 0.0 };
+                    acc += c * c;
+                }
+                (self.noise_variance * acc).sqrt()
+            })
+            .collect()
+    }
+}
+
+/// Séance boursière de 6 h 30 (9 h 30 – 16 h), en minutes
+const SESSION_MINUTES: usize = 390;
+
+/// Longueur de saison par défaut selon la granularité : journée de séance, semaine boursière, année.
+/// Aucune pour les ticks, irréguliers : le modèle se ramène alors à HOLT.
+fn season_length_for(interval: &TimeInterval) -> Option<usize> {
+    match interval {
+        TimeInterval::Tick => None,
+        TimeInterval::Minute => Some(SESSION_MINUTES),
+        TimeInterval::Hour => Some(7),
+        TimeInterval::Day => Some(5),
+        TimeInterval::Week => Some(52),
+        TimeInterval::Month => Some(12),
+    }
+}
+
+/// Prévision du modèle ajusté, accompagnée de ses paramètres de lissage
+fn forecast_with(method: &str, model: &ExponentialSmoothing, history: &[PredictionPoint], config: &PredictionConfig) -> Forecast {
+    let steps = config.model_steps();
+    let z = config.z_score();
+    let forecast = model.forecast(steps);
+    let half_widths: Vec<f64> = model.forecast_std_devs(steps).iter().map(|s| z * s).collect();
+
+    let forecast = Forecast::new(method, config.build_points(history, &forecast, &half_widths))
+        .with_detail("alpha", model.alpha)
+        .with_detail("beta", model.beta);
+    if model.season_length == 0 {
+        return forecast;
+    }
+    forecast.with_detail("gamma", model.gamma).with_detail("season_length", model.season_length)
+}
+
+#[async_trait]
+impl StockPredictor for HoltPredictor {
+    fn method_name(&self) -> &str {
+        "HOLT"
+    }
+
+    fn description(&self) -> &str {
+        "Lissage exponentiel double de Holt (niveau et tendance)"
+    }
+
+    fn min_history(&self) -> usize {
+        3
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
+        self.forecast(history, config).await.points
+    }
+
+    async fn forecast(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Forecast {
+        let closes: Vec<f64> = history.iter().map(|p| p.close).collect();
+        match ExponentialSmoothing::fit(&closes, 0) {
+            Some(model) => forecast_with(self.method_name(), &model, history, config),
+            None => Forecast::new(self.method_name(), vec![]),
+        }
+    }
+}
+
+#[async_trait]
+impl StockPredictor for HoltWintersPredictor {
+    fn method_name(&self) -> &str {
+        "HOLT_WINTERS"
+    }
+
+    fn description(&self) -> &str {
+        "Lissage de Holt-Winters additif, saison déduite de l'intervalle"
+    }
+
+    fn parameters(&self) -> Vec<ParameterSpec> {
+        vec![ParameterSpec::integer("season_length", "Longueur de saison (déduite de l'intervalle par défaut)", None, 2, 366)]
+    }
+
+    fn min_history(&self) -> usize {
+        3
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
+        self.forecast(history, config).await.points
+    }
+
+    async fn forecast(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Forecast {
+        let closes: Vec<f64> = history.iter().map(|p| p.close).collect();
+        let season_length = config
+            .integer_parameter("season_length")
+            .or_else(|| season_length_for(&config.interval))
+            .unwrap_or(0);
+
+        // Sans deux saisons complètes, la composante saisonnière n'est pas identifiable
+        match ExponentialSmoothing::fit(&closes, season_length).or_else(|| ExponentialSmoothing::fit(&closes, 0)) {
+            Some(model) => forecast_with(self.method_name(), &model, history, config),
+            None => Forecast::new(self.method_name(), vec![]),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use crate::application::predicators::prediction_config::PredictionConfig;
+use crate::application::predicators::stock_predictor::StockPredictor;
+use crate::domain::forecast::Forecast;
+use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::statistics;
+
+/// Filtre de Kalman sur un modèle à tendance locale linéaire
+pub struct KalmanPredictor;
+
+/// Modèle espace d'état à tendance locale linéaire :
+/// y_t = μ_t + ε_t,  μ_{t+1} = μ_t + ν_t + η_t,  ν_{t+1} = ν_t + ζ_t,
+/// les trois variances de bruit étant estimées par maximum de vraisemblance
+#[derive(Debug, Clone)]
+pub struct LocalLinearTrend {
+    pub observation_variance: f64,
+    pub level_variance: f64,
+    pub slope_variance: f64,
+    pub log_likelihood: f64,
+    /// État filtré (niveau, pente) au dernier point
+    pub state: [f64; 2],
+    /// Covariance de l'état filtré au dernier point
+    pub covariance: [[f64; 2]; 2],
+}
+
+/// Variance initiale de l'état : a priori quasi diffus
+const DIFFUSE_SCALE: f64 = 1e6;
+/// Observations écartées de la vraisemblance le temps que l'a priori diffus s'efface
+const BURN_IN: usize = 2;
+
+impl LocalLinearTrend {
+    pub fn fit(series: &[f64]) -> Option<Self> {
+        if series.len() < BURN_IN + 3 {
+            return None;
+        }
+        let diffs = statistics::difference(series);
+        let scale = statistics::mean(&diffs.iter().map(|d| d * d).collect::<Vec<_>>()).max(1e-12);
+
+        // Variances paramétrées par leur logarithme pour rester positives
+        let start = [(scale / 2.0).ln(), (scale / 2.0).ln(), (scale / 100.0).ln()];
+        let best = statistics::nelder_mead(
+            |raw| -Self::filter(series, raw[0].exp(), raw[1].exp(), raw[2].exp(), scale).log_likelihood,
+            &start,
+            1.0,
+            400,
+        );
+
+        let model = Self::filter(series, best[0].exp(), best[1].exp(), best[2].exp(), scale);
+        model.log_likelihood.is_finite().then_some(model)
+    }
+
+    /// Passe de filtrage complète ; `scale` fixe l'ordre de grandeur de l'a priori diffus
+    fn filter(series: &[f64], observation_variance: f64, level_variance: f64, slope_variance: f64, scale: f64) -> Self {
+        let mut state = [series[0], 0.0];
+        let mut covariance = [[DIFFUSE_SCALE * scale, 0.0], [0.0, DIFFUSE_SCALE * scale]];
+        let mut log_likelihood = 0.0;
+
+        for (t, y) in series.iter().enumerate() {
+            if t > 0 {
+                (state, covariance) = predict_state(state, covariance, level_variance, slope_variance);
+            }
+
+            // Mise à jour : innovation v = y - μ, de variance S = P00 + σ²ε
+            let innovation = y - state[0];
+            let innovation_variance = covariance[0][0] + observation_variance;
+            let gain = [covariance[0][0] / innovation_variance, covariance[1][0] / innovation_variance];
+
+            state = [state[0] + gain[0] * innovation, state[1] + gain[1] * innovation];
+            let row = covariance[0];
+            for (i, k) in gain.iter().enumerate() {
+                for (j, p) in row.iter().enumerate() {
+                    covariance[i][j] -= k * p;
+                }
+            }
+
+            if t >= BURN_IN {
+                log_likelihood -= 0.5
+                    * ((2.0 * std::f64::consts::PI).ln() + innovation_variance.ln() + innovation * innovation / innovation_variance);
+            }
+        }
+
+        Self {
+            observation_variance,
+            level_variance,
+            slope_variance,
+            log_likelihood,
+            state,
+            covariance,
+        }
+    }
+
+    /// Moyennes et variances prédictives des observations pour h = 1..horizon
+    pub fn forecast(&self, horizon: usize) -> (Vec<f64>, Vec<f64>) {
+        let (mut state, mut covariance) = (self.state, self.covariance);
+        (0..horizon)
+            .map(|_| {
+                (state, covariance) = predict_state(state, covariance, self.level_variance, self.slope_variance);
+                (state[0], covariance[0][0] + self.observation_variance)
+            })
+            .unzip()
+    }
+}
+
+/// Étape de prédiction : x ← F x, P ← F P Fᵀ + Q avec F = [[1, 1], [0, 1]]
+fn predict_state(state: [f64; 2], p: [[f64; 2]; 2], level_variance: f64, slope_variance: f64) -> ([f64; 2], [[f64; 2]; 2]) {
+    let state = [state[0] + state[1], state[1]];
+    let covariance = [
+        [p[0][0] + p[0][1] + p[1][0] + p[1][1] + level_variance, p[0][1] + p[1][1]],
+        [p[1][0] + p[1][1], p[1][1] + slope_variance],
+    ];
+    (state, covariance)
+}
+
+#[async_trait]
+impl StockPredictor for KalmanPredictor {
+    fn method_name(&self) -> &str {
+        "KALMAN"
+    }
+
+    fn description(&self) -> &str {
+        "Filtre de Kalman à tendance locale linéaire, variances estimées par maximum de vraisemblance"
+    }
+
+    fn min_history(&self) -> usize {
+        BURN_IN + 3
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
+        self.forecast(history, config).await.points
+    }
+
+    async fn forecast(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Forecast {
+        let closes: Vec<f64> = history.iter().map(|p| p.close).collect();
+        let model = match LocalLinearTrend::fit(&closes) {
+            Some(model) => model,
+            None => return Forecast::new(self.method_name(), vec![]),
+        };
+
+        let z = config.z_score();
+        let (forecast, variances) = model.forecast(config.model_steps());
+        let half_widths: Vec<f64> = variances.iter().map(|v| z * v.sqrt()).collect();
+
+        Forecast::new(self.method_name(), config.build_points(history, &forecast, &half_widths))
+            .with_detail("observation_variance", model.observation_variance)
+            .with_detail("level_variance", model.level_variance)
+            .with_detail("slope_variance", model.slope_variance)
+            .with_detail("log_likelihood", model.log_likelihood)
+    }
+}
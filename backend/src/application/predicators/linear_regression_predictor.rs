@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use chrono::{Utc, Duration};
+use crate::application::predicators::prediction_config::PredictionConfig;
 use crate::application::predicators::stock_predictor::StockPredictor;
 use crate::domain::prediction_point::PredictionPoint;
 
@@ -11,7 +11,15 @@ impl StockPredictor for LinearRegressionPredictor {
         "LINEAR_REGRESSION"
     }
 
-    async fn predict(&self, history: &[PredictionPoint]) -> Vec<PredictionPoint> {
+    fn description(&self) -> &str {
+        "Tendance linéaire par moindres carrés avec intervalle de prévision"
+    }
+
+    fn min_history(&self) -> usize {
+        2
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
         if history.len() < 2 {
             return vec![];
         }
@@ -25,12 +33,26 @@ impl StockPredictor for LinearRegressionPredictor {
         let slope = (n * sum_xy - sum_x * sum_y) / (n * sum_x2 - sum_x.powi(2));
         let intercept = (sum_y - slope * sum_x) / n;
 
-        (1..=31)
-            .map(|i| {
-                let ts = Utc::now() + Duration::days(i);
-                let pred = intercept + slope * (history.len() as f64 + i as f64 - 1.0);
-                PredictionPoint::new(ts, pred, pred, pred)
+        // Intervalle de prévision classique : s·sqrt(1 + 1/n + (x0 - x̄)² / Sxx)
+        let mean_x = sum_x / n;
+        let sxx = sum_x2 - n * mean_x.powi(2);
+        let sse: f64 = history
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (p.close - intercept - slope * i as f64).powi(2))
+            .sum();
+        let s = if history.len() > 2 { (sse / (n - 2.0)).sqrt() } else { 0.0 };
+        let z = config.z_score();
+
+        let (values, half_widths): (Vec<f64>, Vec<f64>) = (1..=config.model_steps())
+            .map(|h| {
+                let x0 = history.len() as f64 + h as f64 - 1.0;
+                let pred = intercept + slope * x0;
+                let se = s * (1.0 + 1.0 / n + (x0 - mean_x).powi(2) / sxx).sqrt();
+                (pred, z * se)
             })
-            .collect()
+            .unzip();
+
+        config.build_points(history, &values, &half_widths)
     }
 }
@@ -1,10 +1,22 @@
 pub mod stock_predictor;
+pub mod prediction_config;
+pub mod parameter_spec;
 pub mod sma_predictor;
 pub mod naive_predictor;
 pub mod ema_predictor;
 pub mod linear_regression_predictor;
 pub mod ar_predictor;
+pub mod arima_predictor;
+pub mod holt_winters_predictor;
+pub mod kalman_predictor;
+pub mod monte_carlo_predictor;
+pub mod ensemble_predictor;
+pub mod analog_predictor;
+pub mod return_predictors;
+pub mod returns_adapter;
+pub mod block_bootstrap;
 
 pub use stock_predictor::StockPredictor;
+pub use prediction_config::PredictionConfig;
 pub use sma_predictor::SmaPredictor;
 pub use naive_predictor::NaivePredictor;
\ No newline at end of file
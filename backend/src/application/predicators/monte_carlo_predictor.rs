@@ -0,0 +1,152 @@
+use async_trait::async_trait;
+use rand::Rng;
+use serde::Serialize;
+use crate::application::predicators::parameter_spec::ParameterSpec;
+use crate::application::predicators::prediction_config::PredictionConfig;
+use crate::application::predicators::stock_predictor::StockPredictor;
+use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::statistics;
+
+pub const DEFAULT_SIMULATIONS: usize = 5000;
+pub const MAX_SIMULATIONS: usize = 100_000;
+/// Trajectoires brutes renvoyées au plus par une simulation
+pub const MAX_SAMPLE_PATHS: usize = 100;
+/// Pas simulés (trajectoires × pas) au plus par simulation
+pub const MAX_SIMULATED_STEPS: usize = 20_000_000;
+/// Pas simulés (trajectoires × pas) valant un ajustement ordinaire
+const SIMULATED_STEPS_PER_FIT: usize = 10_000;
+
+/// Coût d'un ajustement simulant `simulations` trajectoires sur `steps` pas
+pub fn simulation_cost(simulations: usize, steps: usize) -> usize {
+    simulations.saturating_mul(steps).div_ceil(SIMULATED_STEPS_PER_FIT).max(1)
+}
+
+/// Simulation de Monte Carlo d'un mouvement brownien géométrique :
+/// S_{t+1} = S_t · exp(m + s·Z), m et s estimés sur les rendements logarithmiques
+pub struct MonteCarloPredictor {
+    simulations: usize,
+}
+
+/// Résultat d'une simulation : trajectoire médiane, bandes de percentiles et échantillon de trajectoires brutes
+#[derive(Debug, Clone, Serialize)]
+pub struct Simulation {
+    pub drift: f64,
+    pub volatility: f64,
+    pub simulations: usize,
+    pub points: Vec<PredictionPoint>,
+    /// Trajectoires brutes, alignées sur les horodatages de `points`
+    pub paths: Vec<Vec<f64>>,
+}
+
+impl MonteCarloPredictor {
+    pub fn new(simulations: usize) -> Self {
+        Self { simulations: simulations.clamp(1, MAX_SIMULATIONS) }
+    }
+
+    /// Simule les trajectoires pas à pas ; seules `sample_paths` trajectoires (au plus
+    /// `MAX_SAMPLE_PATHS`) sont conservées en entier
+    pub fn simulate(&self, history: &[PredictionPoint], config: &PredictionConfig, sample_paths: usize) -> Option<Simulation> {
+        let closes: Vec<f64> = history.iter().map(|p| p.close).collect();
+        let returns = statistics::log_returns(&closes);
+        if returns.len() < 2 {
+            return None;
+        }
+        let last = *closes.last()?;
+        let drift = statistics::mean(&returns);
+        let volatility = statistics::std_dev(&returns);
+
+        let steps = config.model_steps();
+        let tail = (1.0 - config.confidence) / 2.0;
+        let sample_paths = sample_paths.min(MAX_SAMPLE_PATHS).min(self.simulations);
+
+        let mut rng = rand::rng();
+        let mut prices = vec![last; self.simulations];
+        let mut raw_paths = vec![Vec::with_capacity(steps); sample_paths];
+        let (mut median, mut upper, mut lower) = (Vec::with_capacity(steps), Vec::with_capacity(steps), Vec::with_capacity(steps));
+
+        for _ in 0..steps {
+            for price in prices.iter_mut() {
+                *price *= (drift + volatility * standard_normal(&mut rng)).exp();
+            }
+            for (path, price) in raw_paths.iter_mut().zip(&prices) {
+                path.push(*price);
+            }
+
+            let mut sorted = prices.clone();
+            sorted.sort_by(f64::total_cmp);
+            median.push(statistics::quantile_sorted(&sorted, 0.5));
+            upper.push(statistics::quantile_sorted(&sorted, 1.0 - tail));
+            lower.push(statistics::quantile_sorted(&sorted, tail));
+        }
+
+        let kept = config.select_steps(&median);
+        let paths = raw_paths
+            .into_iter()
+            .map(|path| kept.iter().map(|i| path[*i]).collect())
+            .collect();
+
+        Some(Simulation {
+            drift,
+            volatility,
+            simulations: self.simulations,
+            points: config.build_banded_points(history, &median, &upper, &lower),
+            paths,
+        })
+    }
+}
+
+impl Default for MonteCarloPredictor {
+    fn default() -> Self {
+        Self::new(DEFAULT_SIMULATIONS)
+    }
+}
+
+/// Tirage N(0, 1) par la méthode de Box-Muller
+fn standard_normal<R: Rng>(rng: &mut R) -> f64 {
+    let u1: f64 = 1.0 - rng.random::<f64>();
+    let u2: f64 = rng.random::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+#[async_trait]
+impl StockPredictor for MonteCarloPredictor {
+    fn method_name(&self) -> &str {
+        "MONTE_CARLO"
+    }
+
+    fn description(&self) -> &str {
+        "Simulation de Monte Carlo d'un mouvement brownien géométrique"
+    }
+
+    fn parameters(&self) -> Vec<ParameterSpec> {
+        vec![ParameterSpec::integer("simulations", "Nombre de trajectoires simulées", Some(self.simulations), 1, MAX_SIMULATIONS)]
+    }
+
+    fn min_history(&self) -> usize {
+        3
+    }
+
+    fn fit_cost(&self, config: &PredictionConfig) -> usize {
+        simulation_cost(config.integer_parameter("simulations").unwrap_or(self.simulations), config.model_steps())
+    }
+
+    /// Stochastique : écartée de l'ensemble
+    fn suits_ensemble(&self) -> bool {
+        false
+    }
+
+    fn models_returns(&self) -> bool {
+        true
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
+        let simulation = match config.integer_parameter("simulations") {
+            Some(simulations) => MonteCarloPredictor::new(simulations).simulate(history, config, 0),
+            None => self.simulate(history, config, 0),
+        };
+        match simulation {
+            Some(simulation) => simulation.points,
+            None => vec![],
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use async_trait::async_trait;
-use chrono::{Utc, Duration};
+use crate::application::predicators::prediction_config::PredictionConfig;
 use crate::application::predicators::stock_predictor::StockPredictor;
 use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::statistics;
 
 pub struct NaivePredictor;
 
@@ -11,14 +12,22 @@ impl StockPredictor for NaivePredictor {
         "NAIVE"
     }
 
-    async fn predict(&self, history: &[PredictionPoint]) -> Vec<PredictionPoint> {
+    fn description(&self) -> &str {
+        "Marche aléatoire : dernier cours prolongé, bande en σ·√h"
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
         let last_price = history.last().map(|p| p.close).unwrap_or(100.0);
 
-        (1..=30)
-            .map(|i| {
-                let ts = Utc::now() + Duration::days(i);
-                PredictionPoint::new(ts, last_price, last_price, last_price)
-            })
-            .collect()
+        // Marche aléatoire : l'erreur à h pas a pour écart-type σ·√h, σ estimé sur les variations
+        let closes: Vec<f64> = history.iter().map(|p| p.close).collect();
+        let sigma = statistics::rms(&statistics::difference(&closes));
+        let z = config.z_score();
+
+        let steps = config.model_steps();
+        let values = vec![last_price; steps];
+        let half_widths: Vec<f64> = (1..=steps).map(|h| z * sigma * (h as f64).sqrt()).collect();
+
+        config.build_points(history, &values, &half_widths)
     }
 }
\ No newline at end of file
@@ -0,0 +1,59 @@
+use serde::Serialize;
+
+/// Type d'un paramètre réglable de prédicteur
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParameterKind {
+    Integer,
+    Float,
+}
+
+/// Description d'un paramètre réglable, exposée par le catalogue des prédicteurs
+#[derive(Debug, Clone, Serialize)]
+pub struct ParameterSpec {
+    pub name: &'static str,
+    pub kind: ParameterKind,
+    pub description: &'static str,
+    /// Valeur par défaut ; absente lorsque le prédicteur la déduit de l'historique
+    pub default: Option<f64>,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl ParameterSpec {
+    pub fn integer(name: &'static str, description: &'static str, default: Option<usize>, min: usize, max: usize) -> Self {
+        Self {
+            name,
+            kind: ParameterKind::Integer,
+            description,
+            default: default.map(|d| d as f64),
+            min: min as f64,
+            max: max as f64,
+        }
+    }
+
+    pub fn float(name: &'static str, description: &'static str, default: Option<f64>, min: f64, max: f64) -> Self {
+        Self {
+            name,
+            kind: ParameterKind::Float,
+            description,
+            default,
+            min,
+            max,
+        }
+    }
+
+    /// Vérifie qu'une valeur respecte le type et les bornes (incluses) du paramètre
+    pub fn check(&self, value: f64) -> Result<(), String> {
+        if !value.is_finite() {
+            return Err(format!("valeur non finie : {}", value));
+        }
+        if self.kind == ParameterKind::Integer && value.fract() != 0.0 {
+            return Err(format!("entier attendu, reçu {}", value));
+        }
+        if value < self.min || value > self.max {
+            return Err(format!("{} hors de l'intervalle [{}, {}]", value, self.min, self.max));
+        }
+        Ok(())
+    }
+}
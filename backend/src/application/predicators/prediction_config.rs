@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::statistics;
+use crate::domain::time_series::TimeInterval;
+use crate::domain::trading_calendar::TradingCalendar;
+
+pub const DEFAULT_CONFIDENCE: f64 = 0.95;
+pub const DEFAULT_HORIZON: usize = 30;
+pub const MAX_HORIZON: usize = 1000;
+/// Borne du nombre de pas unitaires déroulés (`horizon × step`)
+pub const MAX_MODEL_STEPS: usize = 5000;
+pub const DEFAULT_RESAMPLES: usize = 1000;
+pub const MAX_RESAMPLES: usize = 10_000;
+
+/// Origine des bandes upper/lower
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BandModel {
+    /// Bandes calculées par le prédicteur lui-même
+    #[default]
+    Model,
+    /// Bandes redimensionnées par la volatilité prévue d'un GARCH(1,1) sur les rendements
+    Garch,
+    /// Bandes tirées d'un bootstrap par blocs mobiles des résidus à un pas
+    Bootstrap,
+}
+
+/// Grandeur modélisée par le prédicteur
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModelTarget {
+    /// Cours de clôture bruts
+    #[default]
+    Price,
+    /// Rendements logarithmiques, recomposés ensuite en trajectoire de prix
+    Returns,
+}
+
+/// Historique d'un autre symbole mis à disposition des prédicteurs (analogues…)
+#[derive(Debug, Clone)]
+pub struct ReferenceSeries {
+    pub symbol: String,
+    pub points: Vec<PredictionPoint>,
+}
+
+/// Paramètres communs transmis à chaque prédicteur
+#[derive(Debug, Clone)]
+pub struct PredictionConfig {
+    /// Niveau de confiance des bandes upper/lower, dans ]0, 1[
+    pub confidence: f64,
+    /// Nombre de points prédits
+    pub horizon: usize,
+    /// Nombre d'intervalles entre deux points prédits
+    pub step: u32,
+    /// Granularité de l'historique (un pas de modèle = un intervalle)
+    pub interval: TimeInterval,
+    pub calendar: TradingCalendar,
+    pub bands: BandModel,
+    pub target: ModelTarget,
+    /// Nombre de rééchantillonnages du bootstrap (bandes `Bootstrap`)
+    pub resamples: usize,
+    /// Hyperparamètres de la méthode, validés contre ses `ParameterSpec`
+    pub parameters: BTreeMap<String, f64>,
+    /// Séries d'autres symboles, à la même granularité que l'historique
+    pub references: Vec<ReferenceSeries>,
+}
+
+impl PredictionConfig {
+    pub fn new(confidence: f64, horizon: usize, step: u32, interval: TimeInterval) -> Self {
+        Self {
+            confidence,
+            horizon,
+            step: step.max(1),
+            interval,
+            calendar: TradingCalendar,
+            bands: BandModel::Model,
+            target: ModelTarget::Price,
+            resamples: DEFAULT_RESAMPLES,
+            parameters: BTreeMap::new(),
+            references: Vec::new(),
+        }
+    }
+
+    /// Valeur d'un hyperparamètre fourni avec la requête
+    pub fn parameter(&self, name: &str) -> Option<f64> {
+        self.parameters.get(name).copied()
+    }
+
+    pub fn integer_parameter(&self, name: &str) -> Option<usize> {
+        self.parameter(name).map(|v| v as usize)
+    }
+
+    /// Quantile normal bilatéral associé au niveau de confiance (1.96 pour 95 %)
+    pub fn z_score(&self) -> f64 {
+        statistics::normal_quantile(0.5 + self.confidence / 2.0)
+    }
+
+    /// Nombre de pas unitaires que le modèle doit dérouler pour couvrir l'horizon
+    pub fn model_steps(&self) -> usize {
+        self.horizon * self.step as usize
+    }
+
+    /// Prochaines séances de cotation après le dernier point de l'historique
+    pub fn timestamps(&self, history: &[PredictionPoint]) -> Vec<DateTime<Utc>> {
+        let last = history.last().map(|p| p.timestamp).unwrap_or_else(Utc::now);
+        self.calendar.next_sessions(last, &self.interval, self.step, self.horizon)
+    }
+
+    /// Assemble les points prédits à partir de trajectoires à pas unitaire
+    /// (`values` et `half_widths` de longueur `model_steps`), un point tous les `step` pas.
+    pub fn build_points(&self, history: &[PredictionPoint], values: &[f64], half_widths: &[f64]) -> Vec<PredictionPoint> {
+        let upper: Vec<f64> = values.iter().zip(half_widths).map(|(v, w)| v + w).collect();
+        let lower: Vec<f64> = values.iter().zip(half_widths).map(|(v, w)| v - w).collect();
+        self.build_banded_points(history, values, &upper, &lower)
+    }
+
+    /// Variante de `build_points` pour des bandes asymétriques
+    pub fn build_banded_points(&self, history: &[PredictionPoint], values: &[f64], upper: &[f64], lower: &[f64]) -> Vec<PredictionPoint> {
+        self.select_steps(values)
+            .into_iter()
+            .zip(self.timestamps(history))
+            .map(|(i, ts)| PredictionPoint::new(ts, values[i], upper[i], lower[i]))
+            .collect()
+    }
+
+    /// Indices des pas unitaires retenus (un tous les `step`), bornés par la longueur disponible
+    pub fn select_steps<T>(&self, values: &[T]) -> Vec<usize> {
+        let step = self.step as usize;
+        (1..=self.horizon)
+            .map(|k| k * step - 1)
+            .take_while(|i| *i < values.len())
+            .collect()
+    }
+}
+
+impl Default for PredictionConfig {
+    fn default() -> Self {
+        Self::new(DEFAULT_CONFIDENCE, DEFAULT_HORIZON, 1, TimeInterval::Day)
+    }
+}
@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use rand::Rng;
+use crate::application::predicators::monte_carlo_predictor::{simulation_cost, MAX_SIMULATIONS};
+use crate::application::predicators::parameter_spec::ParameterSpec;
+use crate::application::predicators::prediction_config::PredictionConfig;
+use crate::application::predicators::stock_predictor::StockPredictor;
+use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::statistics;
+
+const DEFAULT_BOOTSTRAP_PATHS: usize = 2000;
+
+/// Dérive et volatilité estimées sur les rendements logarithmiques :
+/// médiane P_0·exp(μh) et bande log-normale exp(±zσ√h)
+pub struct DriftPredictor;
+
+/// Rééchantillonnage des rendements logarithmiques historiques en trajectoires de prix ;
+/// la médiane et les percentiles des trajectoires donnent la prévision et ses bandes
+pub struct BootstrapPredictor;
+
+/// Rendements logarithmiques des `window` derniers intervalles (tous par défaut)
+fn recent_returns(history: &[PredictionPoint], config: &PredictionConfig) -> Vec<f64> {
+    let closes: Vec<f64> = history.iter().map(|p| p.close).collect();
+    let mut returns = statistics::log_returns(&closes);
+    if let Some(window) = config.integer_parameter("window") {
+        returns = returns.split_off(returns.len().saturating_sub(window));
+    }
+    returns
+}
+
+fn window_parameter() -> ParameterSpec {
+    ParameterSpec::integer("window", "Nombre de derniers rendements utilisés (tous par défaut)", None, 2, 5000)
+}
+
+#[async_trait]
+impl StockPredictor for DriftPredictor {
+    fn method_name(&self) -> &str {
+        "DRIFT"
+    }
+
+    fn description(&self) -> &str {
+        "Dérive et volatilité des rendements logarithmiques, bandes log-normales"
+    }
+
+    fn parameters(&self) -> Vec<ParameterSpec> {
+        vec![window_parameter()]
+    }
+
+    fn min_history(&self) -> usize {
+        3
+    }
+
+    fn models_returns(&self) -> bool {
+        true
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
+        let returns = recent_returns(history, config);
+        let Some(last) = history.last().map(|p| p.close) else {
+            return vec![];
+        };
+        if returns.len() < 2 {
+            return vec![];
+        }
+        let drift = statistics::mean(&returns);
+        let volatility = statistics::std_dev(&returns);
+        let z = config.z_score();
+
+        let steps = config.model_steps();
+        let (mut values, mut upper, mut lower) = (Vec::with_capacity(steps), Vec::with_capacity(steps), Vec::with_capacity(steps));
+        for h in 1..=steps {
+            let median = last * (drift * h as f64).exp();
+            let spread = z * volatility * (h as f64).sqrt();
+            values.push(median);
+            upper.push(median * spread.exp());
+            lower.push(median * (-spread).exp());
+        }
+
+        config.build_banded_points(history, &values, &upper, &lower)
+    }
+}
+
+#[async_trait]
+impl StockPredictor for BootstrapPredictor {
+    fn method_name(&self) -> &str {
+        "BOOTSTRAP"
+    }
+
+    fn description(&self) -> &str {
+        "Trajectoires obtenues par tirage avec remise des rendements logarithmiques historiques"
+    }
+
+    fn parameters(&self) -> Vec<ParameterSpec> {
+        vec![
+            window_parameter(),
+            ParameterSpec::integer("simulations", "Nombre de trajectoires simulées", Some(DEFAULT_BOOTSTRAP_PATHS), 100, MAX_SIMULATIONS),
+        ]
+    }
+
+    fn min_history(&self) -> usize {
+        3
+    }
+
+    fn fit_cost(&self, config: &PredictionConfig) -> usize {
+        simulation_cost(config.integer_parameter("simulations").unwrap_or(DEFAULT_BOOTSTRAP_PATHS), config.model_steps())
+    }
+
+    /// Stochastique : écartée de l'ensemble
+    fn suits_ensemble(&self) -> bool {
+        false
+    }
+
+    fn models_returns(&self) -> bool {
+        true
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
+        let returns = recent_returns(history, config);
+        let Some(last) = history.last().map(|p| p.close) else {
+            return vec![];
+        };
+        if returns.len() < 2 {
+            return vec![];
+        }
+        let simulations = config.integer_parameter("simulations").unwrap_or(DEFAULT_BOOTSTRAP_PATHS);
+        let tail = (1.0 - config.confidence) / 2.0;
+
+        let mut rng = rand::rng();
+        let mut log_prices = vec![0.0; simulations];
+        let steps = config.model_steps();
+        let (mut median, mut upper, mut lower) = (Vec::with_capacity(steps), Vec::with_capacity(steps), Vec::with_capacity(steps));
+
+        for _ in 0..steps {
+            for log_price in log_prices.iter_mut() {
+                *log_price += returns[rng.random_range(0..returns.len())];
+            }
+            let mut sorted = log_prices.clone();
+            sorted.sort_by(f64::total_cmp);
+            median.push(last * statistics::quantile_sorted(&sorted, 0.5).exp());
+            upper.push(last * statistics::quantile_sorted(&sorted, 1.0 - tail).exp());
+            lower.push(last * statistics::quantile_sorted(&sorted, tail).exp());
+        }
+
+        config.build_banded_points(history, &median, &upper, &lower)
+    }
+}
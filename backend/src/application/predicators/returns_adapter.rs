@@ -0,0 +1,113 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::application::predicators::parameter_spec::ParameterSpec;
+use crate::application::predicators::prediction_config::{ModelTarget, PredictionConfig};
+use crate::application::predicators::stock_predictor::StockPredictor;
+use crate::domain::forecast::Forecast;
+use crate::domain::prediction_point::PredictionPoint;
+
+/// Applique un prédicteur aux rendements logarithmiques plutôt qu'aux cours (mode `returns`).
+/// Le prédicteur est ajusté à pas unitaire sur les rendements, puis la trajectoire de prix
+/// P_h = P_0·exp(Σ r_i) est recomposée ; les écarts-types des rendements prévus, supposés
+/// indépendants, se cumulent en une bande log-normale autour de cette médiane.
+pub struct ReturnsAdapter {
+    inner: Arc<dyn StockPredictor>,
+}
+
+impl ReturnsAdapter {
+    pub fn new(inner: Arc<dyn StockPredictor>) -> Self {
+        Self { inner }
+    }
+}
+
+/// Rendements logarithmiques entre points consécutifs de cours strictement positifs
+fn log_returns(points: &[PredictionPoint]) -> Vec<PredictionPoint> {
+    points
+        .windows(2)
+        .filter(|w| w[0].close > 0.0 && w[1].close > 0.0)
+        .map(|w| PredictionPoint::new(w[1].timestamp, (w[1].close / w[0].close).ln(), 0.0, 0.0))
+        .collect()
+}
+
+/// Configuration du prédicteur sur les rendements : pas unitaire, séries de référence passées
+/// dans le même espace que l'historique
+fn unit_config(config: &PredictionConfig) -> PredictionConfig {
+    let mut unit = config.clone();
+    unit.horizon = config.model_steps();
+    unit.step = 1;
+    for reference in &mut unit.references {
+        reference.points = log_returns(&reference.points);
+    }
+    unit
+}
+
+#[async_trait]
+impl StockPredictor for ReturnsAdapter {
+    fn method_name(&self) -> &str {
+        self.inner.method_name()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn parameters(&self) -> Vec<ParameterSpec> {
+        self.inner.parameters()
+    }
+
+    /// Un point d'historique est consommé par la différenciation
+    fn min_history(&self) -> usize {
+        self.inner.min_history() + 1
+    }
+
+    fn required_history(&self, config: &PredictionConfig) -> usize {
+        self.inner.required_history(config) + 1
+    }
+
+    fn fit_cost(&self, config: &PredictionConfig) -> usize {
+        self.inner.fit_cost(config)
+    }
+
+    async fn frozen(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Option<Arc<dyn StockPredictor>> {
+        let inner = self.inner.frozen(&log_returns(history), &unit_config(config)).await?;
+        Some(Arc::new(ReturnsAdapter::new(inner)))
+    }
+
+    fn models_returns(&self) -> bool {
+        true
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
+        self.forecast(history, config).await.points
+    }
+
+    async fn forecast(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Forecast {
+        let returns = log_returns(history);
+        let Some(last) = history.last().map(|p| p.close) else {
+            return Forecast::new(self.method_name(), vec![]);
+        };
+
+        let on_returns = self.inner.forecast(&returns, &unit_config(config)).await;
+
+        let z = config.z_score();
+        let (mut log_price, mut variance) = (0.0, 0.0);
+        let (mut values, mut upper, mut lower) = (Vec::new(), Vec::new(), Vec::new());
+        for point in &on_returns.points {
+            log_price += point.close;
+            let sigma = (point.upper - point.lower) / (2.0 * z);
+            variance += sigma * sigma;
+
+            let median = last * log_price.exp();
+            values.push(median);
+            upper.push(median * (z * variance.sqrt()).exp());
+            lower.push(median * (-z * variance.sqrt()).exp());
+        }
+
+        Forecast {
+            method: on_returns.method,
+            points: config.build_banded_points(history, &values, &upper, &lower),
+            details: on_returns.details,
+        }
+        .with_detail("target", ModelTarget::Returns)
+    }
+}
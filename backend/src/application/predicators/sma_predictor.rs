@@ -1,33 +1,52 @@
 use async_trait::async_trait;
-use chrono::{Utc, Duration};
+use crate::application::predicators::parameter_spec::ParameterSpec;
+use crate::application::predicators::prediction_config::PredictionConfig;
 use crate::application::predicators::stock_predictor::StockPredictor;
 use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::statistics;
 
 pub struct SmaPredictor;
 
+const WINDOW: usize = 20;
+
 #[async_trait]
 impl StockPredictor for SmaPredictor {
     fn method_name(&self) -> &str {
         "SMA"
     }
 
-    async fn predict(&self, history: &[PredictionPoint]) -> Vec<PredictionPoint> {
+    fn description(&self) -> &str {
+        "Moyenne mobile simple des derniers cours"
+    }
+
+    fn parameters(&self) -> Vec<ParameterSpec> {
+        vec![ParameterSpec::integer("window", "Nombre de derniers cours moyennés", Some(WINDOW), 1, 500)]
+    }
+
+    async fn predict(&self, history: &[PredictionPoint], config: &PredictionConfig) -> Vec<PredictionPoint> {
         if history.is_empty() {
             return vec![];
         }
 
-        let mut closes: Vec<f64> = history.iter().map(|p| p.close).collect();
-        closes.sort_by(|a, b| a.partial_cmp(b).unwrap());
-        closes.reverse();
-        closes.truncate(20);
+        let window = config.integer_parameter("window").unwrap_or(WINDOW);
+        let closes: Vec<f64> = history.iter().map(|p| p.close).collect();
+        let sigma = one_step_error(&closes, window);
+
+        let sma = statistics::mean(&closes[closes.len().saturating_sub(window)..]);
+        let z = config.z_score();
 
-        let sma = closes.iter().copied().sum::<f64>() / closes.len() as f64;
+        let steps = config.model_steps();
+        let values = vec![sma; steps];
+        let half_widths: Vec<f64> = (1..=steps).map(|h| z * sigma * (h as f64).sqrt()).collect();
 
-        (1..=31)
-            .map(|i| {
-                let ts = Utc::now() + Duration::days(i);
-                PredictionPoint::new(ts, sma, sma, sma)
-            })
-            .collect()
+        config.build_points(history, &values, &half_widths)
     }
 }
+
+/// Erreur quadratique moyenne des prévisions in-sample « moyenne des `window` derniers cours »
+fn one_step_error(closes: &[f64], window: usize) -> f64 {
+    let errors: Vec<f64> = (1..closes.len())
+        .map(|t| closes[t] - statistics::mean(&closes[t.saturating_sub(window)..t]))
+        .collect();
+    statistics::rms(&errors)
+}
@@ -1,12 +1,68 @@
+use std::sync::Arc;
 use async_trait::async_trait;
+use crate::application::predicators::parameter_spec::ParameterSpec;
+use crate::application::predicators::prediction_config::PredictionConfig;
+use crate::domain::forecast::Forecast;
 use crate::domain::prediction_point::PredictionPoint;
 
 #[async_trait]
 pub trait StockPredictor: Send + Sync {
     fn method_name(&self) -> &str;
 
+    /// Description courte affichée dans le catalogue
+    fn description(&self) -> &str;
+
+    /// Paramètres réglables de la méthode (aucun par défaut)
+    fn parameters(&self) -> Vec<ParameterSpec> {
+        Vec::new()
+    }
+
+    /// Nombre minimal de points d'historique pour produire une prévision
+    fn min_history(&self) -> usize {
+        1
+    }
+
+    /// Nombre de points d'historique requis pour cette configuration ; `min_history` par défaut
+    fn required_history(&self, _config: &PredictionConfig) -> usize {
+        self.min_history()
+    }
+
+    /// Coût relatif d'un ajustement, en ajustements ordinaires (1 par défaut) ; sert à borner
+    /// les recherches qui enchaînent les ajustements
+    fn fit_cost(&self, _config: &PredictionConfig) -> usize {
+        1
+    }
+
+    /// Membre retenu par défaut dans l'ensemble : méthode déterministe et peu coûteuse
+    fn suits_ensemble(&self) -> bool {
+        true
+    }
+
+    /// Prédicteur à réajuster sur des sous-historiques de `history` (calibrage des bandes
+    /// bootstrap), les choix coûteux faits sur l'historique complet y étant figés ;
+    /// None si la méthode n'en fait aucun
+    async fn frozen(&self, _history: &[PredictionPoint], _config: &PredictionConfig) -> Option<Arc<dyn StockPredictor>> {
+        None
+    }
+
+    /// Vrai si la méthode modélise elle-même les rendements logarithmiques :
+    /// le mode `returns` ne lui est alors pas appliqué
+    fn models_returns(&self) -> bool {
+        false
+    }
+
     async fn predict(
         &self,
         history: &[PredictionPoint],
+        config: &PredictionConfig,
     ) -> Vec<PredictionPoint>;
+
+    /// Prévision accompagnée de ses détails ; par défaut, les seuls points de `predict`
+    async fn forecast(
+        &self,
+        history: &[PredictionPoint],
+        config: &PredictionConfig,
+    ) -> Forecast {
+        Forecast::new(self.method_name(), self.predict(history, config).await)
+    }
 }
@@ -1,6 +1,67 @@
+use std::collections::BTreeMap;
+use std::fmt;
 use std::sync::Arc;
+use serde::Serialize;
 use crate::domain::prediction_point::PredictionPoint;
-use crate::application::predicators::StockPredictor;
+use crate::application::predicators::block_bootstrap::BlockBootstrapPredictor;
+use crate::application::predicators::parameter_spec::ParameterSpec;
+use crate::application::predicators::prediction_config::{BandModel, ModelTarget};
+use crate::application::predicators::returns_adapter::ReturnsAdapter;
+use crate::application::predicators::{PredictionConfig, StockPredictor};
+use crate::domain::forecast::Forecast;
+use crate::domain::garch::GarchModel;
+use crate::domain::statistics;
+
+/// Fiche d'un prédicteur exposée par le catalogue
+#[derive(Debug, Clone, Serialize)]
+pub struct PredictorInfo {
+    pub method: String,
+    pub description: String,
+    pub min_history: usize,
+    /// La méthode modélise déjà les rendements (le mode `returns` est sans effet)
+    pub models_returns: bool,
+    pub parameters: Vec<ParameterSpec>,
+}
+
+/// Bandes GARCH, renvoyé dans les détails de la prévision
+#[derive(Debug, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum GarchSummary {
+    Applied { omega: f64, alpha: f64, beta: f64 },
+    /// Bandes du modèle conservées
+    Skipped { reason: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PredictionError {
+    UnknownMethod(String),
+    InsufficientHistory {
+        method: String,
+        required: usize,
+        available: usize,
+    },
+    InvalidParameter {
+        method: String,
+        name: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for PredictionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PredictionError::UnknownMethod(method) => write!(f, "Méthode inconnue : {}", method),
+            PredictionError::InsufficientHistory { method, required, available } => write!(
+                f,
+                "Historique trop court pour {} : {} points fournis, {} requis",
+                method, available, required
+            ),
+            PredictionError::InvalidParameter { method, name, reason } => {
+                write!(f, "Paramètre '{}' invalide pour {} : {}", name, method, reason)
+            }
+        }
+    }
+}
 
 pub struct PredictionService {
     predictors: Vec<Arc<dyn StockPredictor>>,
@@ -11,22 +72,119 @@ impl PredictionService {
         Self { predictors }
     }
 
+    /// Noms des méthodes enregistrées
+    pub fn methods(&self) -> Vec<String> {
+        self.predictors.iter().map(|p| p.method_name().to_string()).collect()
+    }
+
+    /// Catalogue des prédicteurs : description, historique minimal et paramètres réglables
+    pub fn catalogue(&self) -> Vec<PredictorInfo> {
+        self.predictors
+            .iter()
+            .map(|p| PredictorInfo {
+                method: p.method_name().to_string(),
+                description: p.description().to_string(),
+                min_history: p.min_history(),
+                models_returns: p.models_returns(),
+                parameters: p.parameters(),
+            })
+            .collect()
+    }
+
+    pub fn find(&self, method: &str) -> Option<&Arc<dyn StockPredictor>> {
+        self.predictors
+            .iter()
+            .find(|p| p.method_name().eq_ignore_ascii_case(method))
+    }
+
     pub async fn predict_from_history(
         &self,
         method: &str,
         history: &[PredictionPoint],
-    ) -> Vec<PredictionPoint> {
-        let predictor = match self.predictors
-            .iter()
-            .find(|p| p.method_name().eq_ignore_ascii_case(method))
-        {
-            Some(p) => p,
-            None => {
-                eprintln!("Predictor '{}' introuvable", method);
-                return vec![];
-            }
+        config: &PredictionConfig,
+    ) -> Result<Forecast, PredictionError> {
+        let predictor = self
+            .find(method)
+            .ok_or_else(|| PredictionError::UnknownMethod(method.to_string()))?;
+
+        Self::validate_parameters(predictor.as_ref(), &config.parameters)?;
+
+        let mut predictor = predictor.clone();
+        if config.target == ModelTarget::Returns && !predictor.models_returns() {
+            predictor = Arc::new(ReturnsAdapter::new(predictor));
+        }
+        if config.bands == BandModel::Bootstrap {
+            predictor = Arc::new(BlockBootstrapPredictor::new(predictor));
+        }
+
+        let required = predictor.required_history(config);
+        if history.len() < required {
+            return Err(PredictionError::InsufficientHistory {
+                method: predictor.method_name().to_string(),
+                required,
+                available: history.len(),
+            });
+        }
+
+        let mut forecast = predictor.forecast(history, config).await;
+
+        if config.bands == BandModel::Garch {
+            forecast = Self::apply_garch_bands(forecast, history, config);
+        }
+        if !config.parameters.is_empty() {
+            forecast = forecast.with_detail("parameters", &config.parameters);
+        }
+        Ok(forecast)
+    }
+
+    /// Chaque hyperparamètre doit être déclaré par la méthode et respecter son type et ses bornes
+    fn validate_parameters(predictor: &dyn StockPredictor, parameters: &BTreeMap<String, f64>) -> Result<(), PredictionError> {
+        let specs = predictor.parameters();
+        for (name, value) in parameters {
+            let invalid = |reason: String| PredictionError::InvalidParameter {
+                method: predictor.method_name().to_string(),
+                name: name.clone(),
+                reason,
+            };
+            let spec = specs.iter().find(|s| s.name == name).ok_or_else(|| {
+                let accepted: Vec<&str> = specs.iter().map(|s| s.name).collect();
+                invalid(format!("paramètre inconnu (acceptés : {})", accepted.join(", ")))
+            })?;
+            spec.check(*value).map_err(invalid)?;
+        }
+        Ok(())
+    }
+
+    /// Remplace les bandes par celles d'un GARCH(1,1) ajusté sur les rendements logarithmiques :
+    /// la variance cumulée jusqu'au pas h donne une bande log-normale autour de la prévision.
+    /// Les bandes du modèle sont conservées si le GARCH ne peut pas être estimé ; le détail
+    /// `garch` indique dans tous les cas les bandes retenues.
+    fn apply_garch_bands(mut forecast: Forecast, history: &[PredictionPoint], config: &PredictionConfig) -> Forecast {
+        let closes: Vec<f64> = history.iter().map(|p| p.close).collect();
+        let Some(garch) = GarchModel::fit(&statistics::log_returns(&closes)) else {
+            let skipped = GarchSummary::Skipped {
+                reason: "GARCH non estimable, bandes du modèle conservées".to_string(),
+            };
+            return forecast.with_detail("garch", skipped);
         };
 
-        predictor.predict(history).await
+        let mut cumulative = 0.0;
+        let cumulative_variances: Vec<f64> = garch
+            .forecast_variances(config.model_steps())
+            .into_iter()
+            .map(|v| {
+                cumulative += v;
+                cumulative
+            })
+            .collect();
+
+        let z = config.z_score();
+        for (point, i) in forecast.points.iter_mut().zip(config.select_steps(&cumulative_variances)) {
+            let spread = z * cumulative_variances[i].sqrt();
+            point.upper = point.close * spread.exp();
+            point.lower = point.close * (-spread).exp();
+        }
+        let applied = GarchSummary::Applied { omega: garch.omega, alpha: garch.alpha, beta: garch.beta };
+        forecast.with_detail("garch", applied)
     }
 }
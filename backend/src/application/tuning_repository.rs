@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use crate::domain::tuning::TuningResult;
+
+#[async_trait]
+pub trait TuningRepository: Send + Sync {
+    async fn get_tuning(&self, symbol: &str, method: &str) -> anyhow::Result<Option<TuningResult>>;
+    async fn list_tunings(&self, symbol: &str) -> anyhow::Result<Vec<TuningResult>>;
+    /// Remplace le résultat précédent pour le même couple symbole / méthode
+    async fn save_tuning(&self, result: &TuningResult) -> anyhow::Result<()>;
+}
@@ -0,0 +1,213 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use chrono::Utc;
+use rand::Rng;
+use crate::application::backtest_service::{BacktestService, BacktestSettings};
+use crate::application::predicators::parameter_spec::{ParameterKind, ParameterSpec};
+use crate::application::predicators::{PredictionConfig, StockPredictor};
+use crate::application::prediction_service::PredictionService;
+use crate::application::tuning_repository::TuningRepository;
+use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::tuning::{SearchStrategy, TuningResult, TuningTrial};
+
+/// Nombre maximal de combinaisons évaluées par une recherche
+pub const MAX_TRIALS: usize = 200;
+/// Coût maximal d'une recherche, en ajustements ordinaires (origines rejouées × coût d'un ajustement)
+pub const MAX_TUNING_COST: usize = 50_000;
+
+#[derive(Debug, Clone)]
+pub struct TuningSettings {
+    pub strategy: SearchStrategy,
+    /// Valeurs testées par paramètre (grille)
+    pub grid_points: usize,
+    /// Nombre de tirages (recherche aléatoire)
+    pub trials: usize,
+    /// Walk-forward utilisé pour noter chaque combinaison
+    pub backtest: BacktestSettings,
+}
+
+/// Recherche des hyperparamètres minimisant le RMSE walk-forward, avec persistance
+/// du meilleur réglage par symbole et par méthode
+pub struct TuningService {
+    prediction_service: Arc<PredictionService>,
+    repository: Arc<dyn TuningRepository>,
+}
+
+impl TuningService {
+    pub fn new(prediction_service: Arc<PredictionService>, repository: Arc<dyn TuningRepository>) -> Self {
+        Self { prediction_service, repository }
+    }
+
+    /// Nombre de combinaisons qu'engendrerait la stratégie, calculé sans les construire.
+    /// `grid_points` doit déjà être borné : seules les valeurs de chaque paramètre sont énumérées.
+    pub fn candidate_count(specs: &[ParameterSpec], settings: &TuningSettings) -> usize {
+        match settings.strategy {
+            SearchStrategy::Grid => specs
+                .iter()
+                .map(|spec| grid_values(spec, settings.grid_points.max(2)).len())
+                .fold(1, usize::saturating_mul),
+            SearchStrategy::Random => settings.trials,
+        }
+    }
+
+    /// Combinaisons à évaluer selon la stratégie
+    pub fn candidates(specs: &[ParameterSpec], settings: &TuningSettings) -> Vec<BTreeMap<String, f64>> {
+        match settings.strategy {
+            SearchStrategy::Grid => specs.iter().fold(vec![BTreeMap::new()], |combinations, spec| {
+                let values = grid_values(spec, settings.grid_points.max(2));
+                combinations
+                    .iter()
+                    .flat_map(|combination| {
+                        values.iter().map(move |value| {
+                            let mut next = combination.clone();
+                            next.insert(spec.name.to_string(), *value);
+                            next
+                        })
+                    })
+                    .collect()
+            }),
+            SearchStrategy::Random => {
+                let mut rng = rand::rng();
+                (0..settings.trials)
+                    .map(|_| {
+                        specs
+                            .iter()
+                            .map(|spec| (spec.name.to_string(), sample_value(spec, rng.random::<f64>())))
+                            .collect()
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Coût de la recherche : pour les valeurs par défaut et chaque combinaison, origines rejouées
+    /// × coût d'un ajustement avec ces paramètres
+    pub fn search_cost(
+        predictor: &dyn StockPredictor,
+        history_len: usize,
+        candidates: &[BTreeMap<String, f64>],
+        settings: &TuningSettings,
+    ) -> usize {
+        let backtest = &settings.backtest;
+        let folds = backtest.fold_count(history_len);
+        let defaults = BTreeMap::new();
+        std::iter::once(&defaults)
+            .chain(candidates)
+            .map(|parameters| {
+                let mut config = PredictionConfig::new(backtest.confidence, backtest.horizon, 1, backtest.interval.clone());
+                config.parameters = parameters.clone();
+                folds.saturating_mul(predictor.fit_cost(&config))
+            })
+            .fold(0, usize::saturating_add)
+    }
+
+    /// Évalue les valeurs par défaut puis chaque combinaison, et enregistre la meilleure.
+    /// Le calcul, intensif, tourne sur les threads bloquants plutôt que sur l'exécuteur asynchrone.
+    pub async fn tune(
+        &self,
+        symbol: &str,
+        predictor: Arc<dyn StockPredictor>,
+        history: Vec<PredictionPoint>,
+        candidates: Vec<BTreeMap<String, f64>>,
+        settings: &TuningSettings,
+    ) -> anyhow::Result<TuningResult> {
+        let method = predictor.method_name().to_string();
+        let backtest = settings.backtest.clone();
+        let mut trials = tokio::task::spawn_blocking(move || {
+            futures::executor::block_on(Self::trials(predictor.as_ref(), &history, candidates, &backtest))
+        })
+        .await?;
+
+        // Les combinaisons sans score passent en fin de tableau
+        trials.sort_by(|a, b| match (a.rmse, b.rmse) {
+            (Some(x), Some(y)) => x.total_cmp(&y),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        });
+
+        let best = &trials[0];
+        let result = TuningResult {
+            symbol: symbol.to_string(),
+            method,
+            strategy: settings.strategy,
+            best_parameters: best.parameters.clone(),
+            best_rmse: best.rmse,
+            trials,
+            tuned_at: Utc::now(),
+        };
+
+        self.repository.save_tuning(&result).await?;
+        Ok(result)
+    }
+
+    /// Score walk-forward des valeurs par défaut puis de chaque combinaison
+    async fn trials(
+        predictor: &dyn StockPredictor,
+        history: &[PredictionPoint],
+        candidates: Vec<BTreeMap<String, f64>>,
+        settings: &BacktestSettings,
+    ) -> Vec<TuningTrial> {
+        let mut trials = Vec::with_capacity(candidates.len() + 1);
+        for parameters in std::iter::once(BTreeMap::new()).chain(candidates) {
+            let backtest = BacktestSettings { parameters, ..settings.clone() };
+            let scorecard = BacktestService::evaluate(predictor, history, &backtest).await;
+            trials.push(TuningTrial {
+                parameters: backtest.parameters,
+                folds: scorecard.folds,
+                rmse: scorecard.metrics.rmse.filter(|r| r.is_finite()),
+                mae: scorecard.metrics.mae,
+            });
+        }
+        trials
+    }
+
+    pub async fn results(&self, symbol: &str) -> anyhow::Result<Vec<TuningResult>> {
+        self.repository.list_tunings(symbol).await
+    }
+
+    /// Meilleurs paramètres enregistrés pour le symbole et la méthode (vide si aucun réglage)
+    pub async fn tuned_parameters(&self, symbol: &str, method: &str) -> BTreeMap<String, f64> {
+        let Some(predictor) = self.prediction_service.find(method) else {
+            return BTreeMap::new();
+        };
+        match self.repository.get_tuning(symbol, predictor.method_name()).await {
+            Ok(Some(result)) => result.best_parameters,
+            Ok(None) => BTreeMap::new(),
+            Err(err) => {
+                eprintln!("Erreur lors de la lecture du réglage : {:?}", err);
+                BTreeMap::new()
+            }
+        }
+    }
+}
+
+/// Les bornes couvrant plus d'un ordre de grandeur sont explorées en échelle logarithmique
+fn is_log_scale(spec: &ParameterSpec) -> bool {
+    spec.min > 0.0 && spec.max / spec.min >= 10.0
+}
+
+/// Valeur du paramètre à la position `u` ∈ [0, 1] de son intervalle
+fn sample_value(spec: &ParameterSpec, u: f64) -> f64 {
+    let value = if is_log_scale(spec) {
+        spec.min * (spec.max / spec.min).powf(u)
+    } else {
+        spec.min + u * (spec.max - spec.min)
+    };
+    match spec.kind {
+        ParameterKind::Integer => value.round().clamp(spec.min, spec.max),
+        ParameterKind::Float => value,
+    }
+}
+
+/// `points` valeurs réparties sur l'intervalle ; tous les entiers si l'intervalle est plus petit
+fn grid_values(spec: &ParameterSpec, points: usize) -> Vec<f64> {
+    if spec.kind == ParameterKind::Integer && spec.max - spec.min < points as f64 {
+        return (spec.min as i64..=spec.max as i64).map(|v| v as f64).collect();
+    }
+    let mut values: Vec<f64> = (0..points)
+        .map(|i| sample_value(spec, i as f64 / (points - 1) as f64))
+        .collect();
+    values.dedup();
+    values
+}
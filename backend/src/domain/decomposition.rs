@@ -0,0 +1,212 @@
+use chrono::{DateTime, Datelike, Month, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use crate::domain::statistics;
+use crate::domain::time_series::{StockPoint, TimeInterval};
+
+/// Nombre d'allers-retours tendance / saisonnalités
+const ITERATIONS: usize = 3;
+
+/// Composante saisonnière, indexée par le calendrier plutôt que par une période fixe
+/// afin de rester alignée malgré les jours fériés
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Seasonality {
+    /// Effet jour de la semaine
+    Weekly,
+    /// Effet mois de l'année
+    Yearly,
+}
+
+impl Seasonality {
+    fn key(&self, timestamp: &DateTime<Utc>) -> usize {
+        match self {
+            Seasonality::Weekly => timestamp.weekday().num_days_from_monday() as usize,
+            Seasonality::Yearly => timestamp.month0() as usize,
+        }
+    }
+
+    fn keys(&self) -> usize {
+        match self {
+            Seasonality::Weekly => 7,
+            Seasonality::Yearly => 12,
+        }
+    }
+
+    fn label(&self, key: usize) -> String {
+        match self {
+            Seasonality::Weekly => Weekday::try_from(key as u8).map(|d| d.to_string()).unwrap_or_default(),
+            Seasonality::Yearly => Month::try_from(key as u8 + 1).map(|m| m.name().to_string()).unwrap_or_default(),
+        }
+    }
+
+    /// Durée d'un cycle en jours calendaires
+    fn cycle_days(&self) -> f64 {
+        match self {
+            Seasonality::Weekly => 7.0,
+            Seasonality::Yearly => 365.25,
+        }
+    }
+
+    /// Saisonnalités observables à cette granularité sur au moins deux cycles complets
+    pub fn detectable(points: &[StockPoint], interval: &TimeInterval) -> Vec<Seasonality> {
+        let span_days = match (points.first(), points.last()) {
+            (Some(first), Some(last)) => (last.timestamp - first.timestamp).num_days() as f64,
+            _ => 0.0,
+        };
+        [Seasonality::Weekly, Seasonality::Yearly]
+            .into_iter()
+            .filter(|s| match s {
+                Seasonality::Weekly => interval.rank() <= TimeInterval::Day.rank(),
+                Seasonality::Yearly => interval.rank() <= TimeInterval::Month.rank(),
+            })
+            .filter(|s| span_days >= 2.0 * s.cycle_days())
+            .collect()
+    }
+}
+
+/// Effet moyen d'une modalité du calendrier (lundi, janvier…)
+#[derive(Debug, Clone, Serialize)]
+pub struct SeasonalEffect {
+    pub label: String,
+    pub effect: f64,
+    /// Nombre de points observés pour cette modalité
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SeasonalComponent {
+    pub seasonality: Seasonality,
+    pub effects: Vec<SeasonalEffect>,
+}
+
+/// Composantes d'un point : observed = trend + seasonal + residual
+#[derive(Debug, Clone, Serialize)]
+pub struct DecompositionPoint {
+    pub timestamp: DateTime<Utc>,
+    pub observed: f64,
+    pub trend: f64,
+    /// Somme des composantes saisonnières
+    pub seasonal: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weekly: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yearly: Option<f64>,
+    pub residual: f64,
+    /// Résidu rapporté à son écart absolu médian (échelle normale) : |score| > 3 signale une anomalie
+    pub residual_score: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Decomposition {
+    /// Nombre de points de la fenêtre LOESS de la tendance
+    pub trend_window: usize,
+    pub components: Vec<SeasonalComponent>,
+    pub points: Vec<DecompositionPoint>,
+}
+
+/// Fenêtre de tendance par défaut : 1,5 cycle de la saisonnalité la plus longue, comme dans STL
+pub fn default_trend_window(points: &[StockPoint], seasonalities: &[Seasonality]) -> usize {
+    let span_days = match (points.first(), points.last()) {
+        (Some(first), Some(last)) => ((last.timestamp - first.timestamp).num_days() as f64).max(1.0),
+        _ => 1.0,
+    };
+    let points_per_day = points.len() as f64 / span_days;
+    let longest = seasonalities.iter().map(|s| s.cycle_days()).fold(7.0, f64::max);
+    let window = (1.5 * longest * points_per_day).ceil() as usize;
+    (window | 1).max(3)
+}
+
+/// Décomposition additive des cours de clôture façon STL à saisonnalités périodiques :
+/// la tendance est lissée par LOESS sur la série désaisonnalisée, chaque saisonnalité est la
+/// moyenne centrée par modalité de la série privée de la tendance et des autres saisonnalités
+pub fn decompose(points: &[StockPoint], seasonalities: &[Seasonality], trend_window: usize) -> Option<Decomposition> {
+    if points.len() < 3 {
+        return None;
+    }
+    let observed: Vec<f64> = points.iter().map(|p| p.close).collect();
+    let keys: Vec<Vec<usize>> = seasonalities
+        .iter()
+        .map(|s| points.iter().map(|p| s.key(&p.timestamp)).collect())
+        .collect();
+
+    let mut seasonal = vec![vec![0.0; points.len()]; seasonalities.len()];
+    let mut effects = vec![Vec::new(); seasonalities.len()];
+    let mut trend = statistics::loess(&observed, trend_window);
+
+    for _ in 0..ITERATIONS {
+        for (c, seasonality) in seasonalities.iter().enumerate() {
+            let partial: Vec<f64> = (0..points.len())
+                .map(|t| {
+                    let others: f64 = (0..seasonalities.len()).filter(|o| *o != c).map(|o| seasonal[o][t]).sum();
+                    observed[t] - trend[t] - others
+                })
+                .collect();
+
+            let overall = statistics::mean(&partial);
+            let mut sums = vec![0.0; seasonality.keys()];
+            let mut counts = vec![0usize; seasonality.keys()];
+            for (key, value) in keys[c].iter().zip(&partial) {
+                sums[*key] += value;
+                counts[*key] += 1;
+            }
+            let means: Vec<f64> = sums
+                .iter()
+                .zip(&counts)
+                .map(|(s, n)| if *n > 0 { s / *n as f64 - overall } else { 0.0 })
+                .collect();
+
+            seasonal[c] = keys[c].iter().map(|k| means[*k]).collect();
+            effects[c] = means
+                .iter()
+                .zip(&counts)
+                .enumerate()
+                .filter(|(_, (_, n))| **n > 0)
+                .map(|(key, (effect, count))| SeasonalEffect {
+                    label: seasonality.label(key),
+                    effect: *effect,
+                    count: *count,
+                })
+                .collect();
+        }
+
+        let deseasonalised: Vec<f64> = (0..points.len())
+            .map(|t| observed[t] - seasonal.iter().map(|s| s[t]).sum::<f64>())
+            .collect();
+        trend = statistics::loess(&deseasonalised, trend_window);
+    }
+
+    let residuals: Vec<f64> = (0..points.len())
+        .map(|t| observed[t] - trend[t] - seasonal.iter().map(|s| s[t]).sum::<f64>())
+        .collect();
+    let mut deviations: Vec<f64> = residuals.iter().map(|r| r.abs()).collect();
+    deviations.sort_by(f64::total_cmp);
+    let scale = 1.4826 * statistics::quantile_sorted(&deviations, 0.5);
+
+    let component = |seasonality: Seasonality, t: usize| {
+        seasonalities.iter().position(|s| *s == seasonality).map(|c| seasonal[c][t])
+    };
+    let decomposed = points
+        .iter()
+        .enumerate()
+        .map(|(t, p)| DecompositionPoint {
+            timestamp: p.timestamp,
+            observed: observed[t],
+            trend: trend[t],
+            seasonal: seasonal.iter().map(|s| s[t]).sum(),
+            weekly: component(Seasonality::Weekly, t),
+            yearly: component(Seasonality::Yearly, t),
+            residual: residuals[t],
+            residual_score: if scale > 0.0 { residuals[t] / scale } else { 0.0 },
+        })
+        .collect();
+
+    Some(Decomposition {
+        trend_window,
+        components: seasonalities
+            .iter()
+            .zip(effects)
+            .map(|(seasonality, effects)| SeasonalComponent { seasonality: *seasonality, effects })
+            .collect(),
+        points: decomposed,
+    })
+}
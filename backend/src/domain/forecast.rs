@@ -0,0 +1,33 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+use crate::domain::prediction_point::PredictionPoint;
+
+/// Prévision complète d'une méthode : points prédits et informations propres au modèle
+#[derive(Debug, Clone, Serialize)]
+pub struct Forecast {
+    pub method: String,
+    pub points: Vec<PredictionPoint>,
+    /// Détails facultatifs (poids d'un ensemble, paramètres estimés…)
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub details: Map<String, Value>,
+}
+
+impl Forecast {
+    pub fn new(method: &str, points: Vec<PredictionPoint>) -> Self {
+        Self {
+            method: method.to_string(),
+            points,
+            details: Map::new(),
+        }
+    }
+
+    pub fn with_detail<T: Serialize>(mut self, key: &str, value: T) -> Self {
+        match serde_json::to_value(value) {
+            Ok(value) => {
+                self.details.insert(key.to_string(), value);
+            }
+            Err(err) => eprintln!("Détail '{}' non sérialisable : {:?}", key, err),
+        }
+        self
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::prediction_point::PredictionPoint;
+
+/// Scores hors échantillon d'une série de prévisions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForecastMetrics {
+    pub observations: usize,
+    pub mae: Option<f64>,
+    pub rmse: Option<f64>,
+    /// Erreur absolue moyenne en pourcentage du réel
+    pub mape: Option<f64>,
+    /// Part des prévisions dont le sens (hausse/baisse vs. dernier cours connu) est le bon,
+    /// parmi celles qui annoncent un sens (une prévision égale au dernier cours n'en annonce pas)
+    pub directional_accuracy: Option<f64>,
+    /// Part des valeurs réelles tombées dans la bande [lower, upper]
+    pub coverage: Option<f64>,
+}
+
+/// Accumule les écarts prévision / réalisé pour en tirer des métriques d'erreur
+#[derive(Debug, Clone, Default)]
+pub struct ForecastEvaluator {
+    count: usize,
+    abs_error_sum: f64,
+    sq_error_sum: f64,
+    pct_error_sum: f64,
+    pct_count: usize,
+    direction_calls: usize,
+    direction_hits: usize,
+    covered: usize,
+}
+
+impl ForecastEvaluator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enregistre une prévision face à la valeur réalisée ;
+    /// `reference` est le dernier cours connu au moment de la prévision.
+    pub fn record(&mut self, predicted: &PredictionPoint, actual: f64, reference: f64) {
+        let error = actual - predicted.close;
+        self.count += 1;
+        self.abs_error_sum += error.abs();
+        self.sq_error_sum += error * error;
+
+        if actual != 0.0 {
+            self.pct_error_sum += (error / actual).abs();
+            self.pct_count += 1;
+        }
+        if predicted.close != reference {
+            self.direction_calls += 1;
+            if (predicted.close > reference) == (actual > reference) {
+                self.direction_hits += 1;
+            }
+        }
+        if actual >= predicted.lower && actual <= predicted.upper {
+            self.covered += 1;
+        }
+    }
+
+    pub fn metrics(&self) -> ForecastMetrics {
+        let n = self.count as f64;
+        let ratio = |value: f64| if self.count > 0 { Some(value / n) } else { None };
+
+        ForecastMetrics {
+            observations: self.count,
+            mae: ratio(self.abs_error_sum),
+            rmse: ratio(self.sq_error_sum).map(f64::sqrt),
+            mape: if self.pct_count > 0 {
+                Some(self.pct_error_sum / self.pct_count as f64 * 100.0)
+            } else {
+                None
+            },
+            directional_accuracy: if self.direction_calls > 0 {
+                Some(self.direction_hits as f64 / self.direction_calls as f64)
+            } else {
+                None
+            },
+            coverage: ratio(self.covered as f64),
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::forecast_metrics::{ForecastEvaluator, ForecastMetrics};
+use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::time_series::{StockPoint, TimeInterval};
+
+/// Prévision archivée, confrontée au fil de l'eau aux cours réellement observés
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForecastRecord {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub symbol: String,
+    pub method: String,
+    /// Hyperparamètres effectivement appliqués (réglages du symbole et surcharges)
+    pub parameters: BTreeMap<String, f64>,
+    pub interval: TimeInterval,
+    pub confidence: f64,
+    #[serde(with = "bson_datetime")]
+    pub created_at: DateTime<Utc>,
+    /// Dernier cours connu au moment de la prévision
+    pub reference: f64,
+    pub points: Vec<PredictionPoint>,
+    /// Cours réalisés des premiers points prévus, dans l'ordre
+    #[serde(default)]
+    pub actuals: Vec<f64>,
+    /// Vrai lorsque tous les points prévus ont été confrontés au réel
+    #[serde(default)]
+    pub complete: bool,
+    pub evaluated_at: Option<DateTime<Utc>>,
+    pub metrics: Option<ForecastMetrics>,
+}
+
+/// Date stockée en date BSON (triable et indexable), exposée en RFC 3339 dans les réponses JSON
+mod bson_datetime {
+    use chrono::{DateTime, Utc};
+    use mongodb::bson;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            value.serialize(serializer)
+        } else {
+            bson::DateTime::from_millis(value.timestamp_millis()).serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+        if deserializer.is_human_readable() {
+            return DateTime::deserialize(deserializer);
+        }
+        let millis = bson::DateTime::deserialize(deserializer)?.timestamp_millis();
+        DateTime::from_timestamp_millis(millis).ok_or_else(|| de::Error::custom(format!("Date hors limites : {} ms", millis)))
+    }
+}
+
+impl ForecastRecord {
+    pub fn new(
+        symbol: &str,
+        method: &str,
+        parameters: BTreeMap<String, f64>,
+        interval: TimeInterval,
+        confidence: f64,
+        reference: f64,
+        points: Vec<PredictionPoint>,
+    ) -> Self {
+        let created_at = Utc::now();
+        Self {
+            id: format!("{}:{}:{}", symbol, method, created_at.timestamp_micros()),
+            symbol: symbol.to_string(),
+            method: method.to_string(),
+            parameters,
+            interval,
+            confidence,
+            created_at,
+            reference,
+            complete: points.is_empty(),
+            points,
+            actuals: Vec::new(),
+            evaluated_at: None,
+            metrics: None,
+        }
+    }
+
+    /// Complète les cours réalisés à partir des bougies triées du symbole : un point prévu est
+    /// réalisé dès que les données l'atteignent, et prend la clôture de la dernière bougie
+    /// antérieure ou égale à sa date. Renvoie le nombre de points nouvellement réalisés.
+    pub fn realise(&mut self, candles: &[StockPoint]) -> usize {
+        let Some(latest) = candles.last().map(|c| c.timestamp) else {
+            return 0;
+        };
+
+        let before = self.actuals.len();
+        for point in &self.points[before..] {
+            if point.timestamp > latest {
+                break;
+            }
+            let index = candles.partition_point(|c| c.timestamp <= point.timestamp);
+            let Some(candle) = index.checked_sub(1).map(|i| &candles[i]) else {
+                break;
+            };
+            self.actuals.push(candle.close);
+        }
+
+        let realised = self.actuals.len() - before;
+        if realised > 0 {
+            self.complete = self.actuals.len() == self.points.len();
+            self.evaluated_at = Some(Utc::now());
+            self.metrics = Some(self.evaluator().metrics());
+        }
+        realised
+    }
+
+    /// Alimente un évaluateur avec les points déjà réalisés
+    pub fn record_into(&self, evaluator: &mut ForecastEvaluator) {
+        for (point, actual) in self.points.iter().zip(&self.actuals) {
+            evaluator.record(point, *actual, self.reference);
+        }
+    }
+
+    fn evaluator(&self) -> ForecastEvaluator {
+        let mut evaluator = ForecastEvaluator::new();
+        self.record_into(&mut evaluator);
+        evaluator
+    }
+}
+
+/// Précision réelle (hors échantillon) d'une méthode, toutes prévisions archivées confondues
+#[derive(Debug, Clone, Serialize)]
+pub struct LeaderboardEntry {
+    pub method: String,
+    /// Absent pour le classement toutes valeurs confondues
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// Prévisions dont au moins un point est réalisé
+    pub forecasts: usize,
+    #[serde(flatten)]
+    pub metrics: ForecastMetrics,
+}
+
+/// Classements par méthode et par couple symbole / méthode, du MAPE le plus faible au plus élevé
+#[derive(Debug, Clone, Serialize)]
+pub struct Leaderboard {
+    pub by_method: Vec<LeaderboardEntry>,
+    pub by_symbol: Vec<LeaderboardEntry>,
+}
+
+impl Leaderboard {
+    pub fn from_records(records: &[ForecastRecord]) -> Self {
+        let mut by_method: BTreeMap<String, (usize, ForecastEvaluator)> = BTreeMap::new();
+        let mut by_symbol: BTreeMap<(String, String), (usize, ForecastEvaluator)> = BTreeMap::new();
+
+        for record in records.iter().filter(|r| !r.actuals.is_empty()) {
+            for (count, evaluator) in [
+                by_method.entry(record.method.clone()).or_default(),
+                by_symbol.entry((record.symbol.clone(), record.method.clone())).or_default(),
+            ] {
+                *count += 1;
+                record.record_into(evaluator);
+            }
+        }
+
+        let entry = |method: String, symbol: Option<String>, (forecasts, evaluator): (usize, ForecastEvaluator)| LeaderboardEntry {
+            method,
+            symbol,
+            forecasts,
+            metrics: evaluator.metrics(),
+        };
+        Self {
+            by_method: Self::ranked(by_method.into_iter().map(|(method, acc)| entry(method, None, acc)).collect()),
+            by_symbol: Self::ranked(
+                by_symbol
+                    .into_iter()
+                    .map(|((symbol, method), acc)| entry(method, Some(symbol), acc))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn ranked(mut entries: Vec<LeaderboardEntry>) -> Vec<LeaderboardEntry> {
+        entries.sort_by(|a, b| match (a.metrics.mape, b.metrics.mape) {
+            (Some(x), Some(y)) => x.total_cmp(&y),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        });
+        entries
+    }
+}
@@ -0,0 +1,108 @@
+use serde::Serialize;
+use crate::domain::statistics;
+
+/// Modèle GARCH(1,1) sur des rendements r_t = μ + ε_t, ε_t ~ N(0, σ²_t),
+/// σ²_t = ω + α·ε²_{t-1} + β·σ²_{t-1}, estimé par maximum de vraisemblance gaussienne
+#[derive(Debug, Clone, Serialize)]
+pub struct GarchModel {
+    pub mean: f64,
+    pub omega: f64,
+    pub alpha: f64,
+    pub beta: f64,
+    pub log_likelihood: f64,
+    /// Variances conditionnelles in-sample, alignées sur les rendements
+    pub conditional_variances: Vec<f64>,
+    last_residual: f64,
+}
+
+impl GarchModel {
+    pub fn fit(returns: &[f64]) -> Option<Self> {
+        if returns.len() < 10 {
+            return None;
+        }
+        let mean = statistics::mean(returns);
+        let residuals: Vec<f64> = returns.iter().map(|r| r - mean).collect();
+        let sample_variance = residuals.iter().map(|e| e * e).sum::<f64>() / residuals.len() as f64;
+        if sample_variance <= 0.0 {
+            return None;
+        }
+
+        // Paramétrage sans contrainte : ω = exp(θ0), persistance α+β = logistic(θ1),
+        // part de α dans la persistance = logistic(θ2) ; garantit ω > 0, α, β ≥ 0 et α+β < 1
+        let decode = |raw: &[f64]| -> (f64, f64, f64) {
+            let persistence = statistics::logistic(raw[1]);
+            let share = statistics::logistic(raw[2]);
+            (raw[0].exp(), persistence * share, persistence * (1.0 - share))
+        };
+
+        let start_persistence: f64 = 0.95;
+        let start_share: f64 = 0.1;
+        let start = [
+            (sample_variance * (1.0 - start_persistence)).ln(),
+            statistics::logit(start_persistence),
+            statistics::logit(start_share),
+        ];
+
+        let best = statistics::nelder_mead(
+            |raw| {
+                let (omega, alpha, beta) = decode(raw);
+                -log_likelihood(&residuals, omega, alpha, beta, sample_variance).0
+            },
+            &start,
+            0.5,
+            500,
+        );
+
+        let (omega, alpha, beta) = decode(&best);
+        let (log_likelihood, conditional_variances) = log_likelihood(&residuals, omega, alpha, beta, sample_variance);
+
+        Some(Self {
+            mean,
+            omega,
+            alpha,
+            beta,
+            log_likelihood,
+            conditional_variances,
+            last_residual: *residuals.last()?,
+        })
+    }
+
+    pub fn persistence(&self) -> f64 {
+        self.alpha + self.beta
+    }
+
+    /// Variance inconditionnelle ω / (1 - α - β)
+    pub fn long_run_variance(&self) -> f64 {
+        self.omega / (1.0 - self.persistence()).max(f64::EPSILON)
+    }
+
+    /// Variances conditionnelles prévues pour h = 1..horizon ;
+    /// au-delà d'un pas, elles convergent géométriquement vers la variance de long terme
+    pub fn forecast_variances(&self, horizon: usize) -> Vec<f64> {
+        let last_variance = self.conditional_variances.last().copied().unwrap_or(self.long_run_variance());
+        let mut variance = self.omega + self.alpha * self.last_residual.powi(2) + self.beta * last_variance;
+        let mut out = Vec::with_capacity(horizon);
+        for _ in 0..horizon {
+            out.push(variance);
+            variance = self.omega + self.persistence() * variance;
+        }
+        out
+    }
+}
+
+/// Log-vraisemblance gaussienne et variances conditionnelles, initialisées à la variance empirique
+fn log_likelihood(residuals: &[f64], omega: f64, alpha: f64, beta: f64, initial_variance: f64) -> (f64, Vec<f64>) {
+    let mut variance = initial_variance;
+    let mut variances = Vec::with_capacity(residuals.len());
+    let mut ll = 0.0;
+
+    for (t, e) in residuals.iter().enumerate() {
+        if t > 0 {
+            variance = omega + alpha * residuals[t - 1].powi(2) + beta * variance;
+        }
+        variances.push(variance);
+        ll -= 0.5 * ((2.0 * std::f64::consts::PI).ln() + variance.ln() + e * e / variance);
+    }
+
+    (ll, variances)
+}
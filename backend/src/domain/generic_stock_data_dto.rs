@@ -1,17 +1,12 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
-use crate::domain::stock_insights::StockInsights;
-use crate::domain::stock_insights_builder::StockInsightsBuilder;
-use crate::domain::time_series::StockSegment;
+use crate::domain::time_series::{StockPoint, StockSegment, TimeInterval};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenericStockDataDTO {
     pub symbol: String,
     pub provider: Option<String>,
     pub last_update: Option<DateTime<Utc>>,
     pub historical_segments: Vec<StockSegment>,
-
-    #[serde(skip)]
-    insights: StockInsights,
 }
 
 impl GenericStockDataDTO {
@@ -21,18 +16,30 @@ impl GenericStockDataDTO {
         last_update: Option<DateTime<Utc>>,
         historical_segments: Vec<StockSegment>,
     ) -> Self {
-        let insights = StockInsightsBuilder::build(&historical_segments);
-
         Self {
             symbol,
             provider,
             last_update,
             historical_segments,
-            insights,
         }
     }
 
-    pub fn insights(&self) -> &StockInsights {
-        &self.insights
+    /// Tous les points des segments, triés chronologiquement
+    pub fn sorted_points(&self) -> Vec<StockPoint> {
+        let mut points = self.historical_segments
+            .iter()
+            .flat_map(|s| s.data_points.clone())
+            .collect::<Vec<_>>();
+        points.sort_by_key(|p| p.timestamp);
+        points
+    }
+
+    /// Granularité du segment le plus récent (Day si aucun segment)
+    pub fn interval(&self) -> TimeInterval {
+        self.historical_segments
+            .iter()
+            .max_by_key(|s| s.end_date)
+            .map(|s| s.interval.clone())
+            .unwrap_or(TimeInterval::Day)
     }
 }
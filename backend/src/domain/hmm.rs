@@ -0,0 +1,220 @@
+use crate::domain::statistics;
+
+/// Chaîne de Markov cachée à émissions gaussiennes, estimée par Baum-Welch
+#[derive(Debug, Clone)]
+pub struct GaussianHmm {
+    pub means: Vec<f64>,
+    pub variances: Vec<f64>,
+    /// transition[i][j] = P(état j à t+1 | état i à t)
+    pub transition: Vec<Vec<f64>>,
+    pub initial: Vec<f64>,
+    pub log_likelihood: f64,
+}
+
+/// Résultat des passes avant-arrière
+struct Smoothing {
+    /// P(état | observations) à chaque instant
+    posteriors: Vec<Vec<f64>>,
+    /// Nombre attendu de transitions i → j
+    transitions: Vec<Vec<f64>>,
+    log_likelihood: f64,
+}
+
+const MAX_ITERATIONS: usize = 200;
+const TOLERANCE: f64 = 1e-6;
+
+impl GaussianHmm {
+    /// Ajuste un modèle à `states` états ; initialisation par quantiles des observations
+    pub fn fit(observations: &[f64], states: usize) -> Option<Self> {
+        if states == 0 || observations.len() < 10 * states {
+            return None;
+        }
+        let total_variance = statistics::std_dev(observations).powi(2);
+        if !(total_variance > 0.0 && total_variance.is_finite()) {
+            return None;
+        }
+        // Plancher évitant qu'un état ne se réduise à un point isolé
+        let variance_floor = total_variance * 1e-3;
+
+        let mut sorted = observations.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let chunk = sorted.len() / states;
+        let (means, variances): (Vec<f64>, Vec<f64>) = (0..states)
+            .map(|s| {
+                let part = &sorted[s * chunk..if s + 1 == states { sorted.len() } else { (s + 1) * chunk }];
+                (statistics::mean(part), statistics::std_dev(part).powi(2).max(variance_floor))
+            })
+            .unzip();
+        let stay = 0.9;
+        let switch = if states > 1 { (1.0 - stay) / (states - 1) as f64 } else { 0.0 };
+        let transition = (0..states)
+            .map(|i| (0..states).map(|j| if i == j { if states > 1 { stay } else { 1.0 } } else { switch }).collect())
+            .collect();
+
+        let mut model = Self {
+            means,
+            variances,
+            transition,
+            initial: vec![1.0 / states as f64; states],
+            log_likelihood: f64::NEG_INFINITY,
+        };
+
+        for _ in 0..MAX_ITERATIONS {
+            let previous = model.log_likelihood;
+            model = model.baum_welch_step(observations, variance_floor)?;
+            if (model.log_likelihood - previous).abs() < TOLERANCE {
+                break;
+            }
+        }
+        Some(model)
+    }
+
+    pub fn states(&self) -> usize {
+        self.means.len()
+    }
+
+    fn emissions(&self, x: f64) -> Vec<f64> {
+        (0..self.states())
+            .map(|s| {
+                let v = self.variances[s];
+                (-(x - self.means[s]).powi(2) / (2.0 * v)).exp() / (2.0 * std::f64::consts::PI * v).sqrt()
+            })
+            .collect()
+    }
+
+    /// Passes avant-arrière normalisées par les facteurs d'échelle de chaque instant
+    fn forward_backward(&self, observations: &[f64]) -> Option<Smoothing> {
+        let (n, k) = (observations.len(), self.states());
+        let emissions: Vec<Vec<f64>> = observations.iter().map(|x| self.emissions(*x)).collect();
+
+        let mut alpha = vec![vec![0.0; k]; n];
+        let mut scales = vec![0.0; n];
+        for t in 0..n {
+            for j in 0..k {
+                let prior = if t == 0 {
+                    self.initial[j]
+                } else {
+                    (0..k).map(|i| alpha[t - 1][i] * self.transition[i][j]).sum()
+                };
+                alpha[t][j] = prior * emissions[t][j];
+            }
+            scales[t] = alpha[t].iter().sum();
+            if !(scales[t] > 0.0 && scales[t].is_finite()) {
+                return None;
+            }
+            alpha[t].iter_mut().for_each(|a| *a /= scales[t]);
+        }
+
+        let mut beta = vec![vec![1.0; k]; n];
+        for t in (0..n - 1).rev() {
+            for i in 0..k {
+                beta[t][i] = (0..k)
+                    .map(|j| self.transition[i][j] * emissions[t + 1][j] * beta[t + 1][j])
+                    .sum::<f64>()
+                    / scales[t + 1];
+            }
+        }
+
+        let posteriors: Vec<Vec<f64>> = (0..n)
+            .map(|t| {
+                let row: Vec<f64> = (0..k).map(|i| alpha[t][i] * beta[t][i]).collect();
+                let total: f64 = row.iter().sum();
+                row.into_iter().map(|g| g / total).collect()
+            })
+            .collect();
+
+        let mut transitions = vec![vec![0.0; k]; k];
+        for t in 0..n - 1 {
+            for i in 0..k {
+                for j in 0..k {
+                    transitions[i][j] +=
+                        alpha[t][i] * self.transition[i][j] * emissions[t + 1][j] * beta[t + 1][j] / scales[t + 1];
+                }
+            }
+        }
+
+        let log_likelihood = scales.iter().map(|c| c.ln()).sum();
+        Some(Smoothing { posteriors, transitions, log_likelihood })
+    }
+
+    fn baum_welch_step(&self, observations: &[f64], variance_floor: f64) -> Option<Self> {
+        let Smoothing { posteriors, transitions, log_likelihood } = self.forward_backward(observations)?;
+        let k = self.states();
+
+        let mut means = vec![0.0; k];
+        let mut variances = vec![0.0; k];
+        for s in 0..k {
+            let weight: f64 = posteriors.iter().map(|g| g[s]).sum();
+            if weight <= 0.0 {
+                return None;
+            }
+            means[s] = posteriors.iter().zip(observations).map(|(g, x)| g[s] * x).sum::<f64>() / weight;
+            variances[s] = (posteriors
+                .iter()
+                .zip(observations)
+                .map(|(g, x)| g[s] * (x - means[s]).powi(2))
+                .sum::<f64>()
+                / weight)
+                .max(variance_floor);
+        }
+
+        let transition = transitions
+            .iter()
+            .map(|row| {
+                let total: f64 = row.iter().sum();
+                row.iter().map(|v| if total > 0.0 { v / total } else { 1.0 / k as f64 }).collect()
+            })
+            .collect();
+
+        Some(Self {
+            means,
+            variances,
+            transition,
+            initial: posteriors[0].clone(),
+            log_likelihood,
+        })
+    }
+
+    /// Probabilités a posteriori P(état | toutes les observations) à chaque instant
+    pub fn posteriors(&self, observations: &[f64]) -> Option<Vec<Vec<f64>>> {
+        self.forward_backward(observations).map(|smoothing| smoothing.posteriors)
+    }
+
+    /// Séquence d'états la plus probable (algorithme de Viterbi, en logarithmes)
+    pub fn viterbi(&self, observations: &[f64]) -> Vec<usize> {
+        let k = self.states();
+        if observations.is_empty() || k == 0 {
+            return Vec::new();
+        }
+        let log_emissions = |x: f64| -> Vec<f64> { self.emissions(x).into_iter().map(f64::ln).collect() };
+        let log_transition: Vec<Vec<f64>> = self.transition.iter().map(|row| row.iter().map(|p| p.ln()).collect()).collect();
+
+        let first = log_emissions(observations[0]);
+        let mut scores: Vec<f64> = (0..k).map(|s| self.initial[s].ln() + first[s]).collect();
+        let mut backpointers = Vec::with_capacity(observations.len());
+
+        for x in &observations[1..] {
+            let emissions = log_emissions(*x);
+            let (next, pointers): (Vec<f64>, Vec<usize>) = (0..k)
+                .map(|j| {
+                    let (best, score) = (0..k)
+                        .map(|i| (i, scores[i] + log_transition[i][j]))
+                        .max_by(|a, b| a.1.total_cmp(&b.1))
+                        .unwrap_or((0, f64::NEG_INFINITY));
+                    (score + emissions[j], best)
+                })
+                .unzip();
+            scores = next;
+            backpointers.push(pointers);
+        }
+
+        let mut state = (0..k).max_by(|a, b| scores[*a].total_cmp(&scores[*b])).unwrap_or(0);
+        let mut path = vec![state];
+        for pointers in backpointers.iter().rev() {
+            state = pointers[state];
+            path.push(state);
+        }
+        path.reverse();
+        path
+    }
+}
@@ -0,0 +1,269 @@
+use std::collections::BTreeMap;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::domain::indicator_stream::{self, StreamingIndicator};
+use crate::domain::indicators;
+use crate::domain::time_series::StockPoint;
+
+/// Période maximale acceptée pour un indicateur
+pub const MAX_PERIOD: usize = 1000;
+
+/// Indicateur demandé, sous la forme "nom" ou "nom:p1:p2…" (ex. "sma:20", "rsi:14", "keltner:20:2:10").
+/// Les paramètres omis prennent leur valeur usuelle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IndicatorSpec {
+    Sma(usize),
+    Ema(usize),
+    Bollinger(usize),
+    Rsi(usize),
+    Macd { fast: usize, slow: usize, signal: usize },
+    Atr(usize),
+    MaxDrawdown(usize),
+    CumulativeGain(usize),
+    VolumeAvg(usize),
+    Volatility(usize),
+    DayChange,
+    DayChangePercent,
+    Stochastic { period: usize, smooth: usize, signal: usize },
+    WilliamsR(usize),
+    Dmi { di: usize, adx: usize },
+    Cci(usize),
+    Obv,
+    Vwap(usize),
+    ChaikinMoneyFlow(usize),
+    Mfi(usize),
+    Keltner { period: usize, multiplier: f64, atr: usize },
+    Donchian(usize),
+    ParabolicSar { start: f64, increment: f64, maximum: f64 },
+    Ichimoku { conversion: usize, base: usize, span_b: usize, displacement: usize },
+    Aroon(usize),
+}
+
+/// Paramètres positionnels d'une spécification
+struct Arguments<'a> {
+    name: &'a str,
+    values: Vec<&'a str>,
+}
+
+impl Arguments<'_> {
+    fn at_most(&self, count: usize) -> Result<(), String> {
+        if self.values.len() > count {
+            return Err(format!("L'indicateur '{}' accepte au plus {} paramètre(s)", self.name, count));
+        }
+        Ok(())
+    }
+
+    fn period(&self, index: usize, default: usize) -> Result<usize, String> {
+        match self.values.get(index) {
+            None => Ok(default),
+            Some(raw) => raw
+                .parse::<usize>()
+                .ok()
+                .filter(|p| (1..=MAX_PERIOD).contains(p))
+                .ok_or_else(|| format!("Période invalide pour '{}' : {} (attendu entre 1 et {})", self.name, raw, MAX_PERIOD)),
+        }
+    }
+
+    fn float(&self, index: usize, default: f64, min: f64, max: f64) -> Result<f64, String> {
+        match self.values.get(index) {
+            None => Ok(default),
+            Some(raw) => raw
+                .parse::<f64>()
+                .ok()
+                .filter(|v| *v >= min && *v <= max)
+                .ok_or_else(|| format!("Paramètre invalide pour '{}' : {} (attendu entre {} et {})", self.name, raw, min, max)),
+        }
+    }
+}
+
+/// Courbes d'un indicateur alignées sur les horodatages ; `None` tant que la fenêtre est incomplète
+#[derive(Debug, Clone, Serialize)]
+pub struct IndicatorSeries {
+    /// Nom canonique ("sma:20")
+    pub name: String,
+    /// Une seule courbe "value", ou plusieurs ("upper"/"lower" pour Bollinger, "k"/"d" pour la stochastique…)
+    pub lines: BTreeMap<&'static str, Vec<Option<f64>>>,
+}
+
+/// Séries demandées pour un historique, partageant un même axe de temps
+#[derive(Debug, Clone, Serialize)]
+pub struct IndicatorChart {
+    pub timestamps: Vec<DateTime<Utc>>,
+    pub indicators: Vec<IndicatorSeries>,
+}
+
+impl IndicatorSpec {
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut parts = spec.split(':').map(str::trim);
+        let name = parts.next().unwrap_or("").to_ascii_lowercase();
+        let args = Arguments { name: &name, values: parts.collect() };
+
+        let (arity, indicator) = match name.as_str() {
+            "sma" => (1, Self::Sma(args.period(0, 20)?)),
+            "ema" => (1, Self::Ema(args.period(0, 20)?)),
+            "bollinger" => (1, Self::Bollinger(args.period(0, 20)?)),
+            "rsi" => (1, Self::Rsi(args.period(0, 14)?)),
+            "macd" => {
+                let (fast, slow) = (args.period(0, 12)?, args.period(1, 26)?);
+                if fast >= slow {
+                    return Err(format!("MACD : la période rapide ({}) doit être inférieure à la lente ({})", fast, slow));
+                }
+                (3, Self::Macd { fast, slow, signal: args.period(2, 9)? })
+            }
+            "atr" => (1, Self::Atr(args.period(0, 14)?)),
+            "max_drawdown" => (1, Self::MaxDrawdown(args.period(0, 30)?)),
+            "cumulative_gain" => (1, Self::CumulativeGain(args.period(0, 30)?)),
+            "volume_avg" => (1, Self::VolumeAvg(args.period(0, 30)?)),
+            "volatility" => (1, Self::Volatility(args.period(0, 30)?)),
+            "day_change" => (0, Self::DayChange),
+            "day_change_percent" => (0, Self::DayChangePercent),
+            "stochastic" => (3, Self::Stochastic { period: args.period(0, 14)?, smooth: args.period(1, 1)?, signal: args.period(2, 3)? }),
+            "williams_r" => (1, Self::WilliamsR(args.period(0, 14)?)),
+            "dmi" | "adx" => (2, Self::Dmi { di: args.period(0, 14)?, adx: args.period(1, 14)? }),
+            "cci" => (1, Self::Cci(args.period(0, 20)?)),
+            "obv" => (0, Self::Obv),
+            "vwap" => (1, Self::Vwap(args.period(0, 20)?)),
+            "cmf" => (1, Self::ChaikinMoneyFlow(args.period(0, 20)?)),
+            "mfi" => (1, Self::Mfi(args.period(0, 14)?)),
+            "keltner" => (3, Self::Keltner {
+                period: args.period(0, 20)?,
+                multiplier: args.float(1, 2.0, 0.1, 10.0)?,
+                atr: args.period(2, 10)?,
+            }),
+            "donchian" => (1, Self::Donchian(args.period(0, 20)?)),
+            "psar" => (3, Self::ParabolicSar {
+                start: args.float(0, 0.02, 0.001, 1.0)?,
+                increment: args.float(1, 0.02, 0.001, 1.0)?,
+                maximum: args.float(2, 0.2, 0.001, 1.0)?,
+            }),
+            "ichimoku" => (4, Self::Ichimoku {
+                conversion: args.period(0, 9)?,
+                base: args.period(1, 26)?,
+                span_b: args.period(2, 52)?,
+                displacement: args.period(3, 26)?,
+            }),
+            "aroon" => (1, Self::Aroon(args.period(0, 14)?)),
+            other => return Err(format!("Indicateur inconnu : {}", other)),
+        };
+        args.at_most(arity)?;
+        Ok(indicator)
+    }
+
+    /// Liste séparée par des virgules ; les doublons sont ignorés
+    pub fn parse_list(specs: &str) -> Result<Vec<Self>, String> {
+        let mut parsed: Vec<Self> = Vec::new();
+        for spec in specs.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let indicator = Self::parse(spec)?;
+            if !parsed.contains(&indicator) {
+                parsed.push(indicator);
+            }
+        }
+        Ok(parsed)
+    }
+
+    pub fn name(&self) -> String {
+        match *self {
+            Self::Sma(p) => format!("sma:{}", p),
+            Self::Ema(p) => format!("ema:{}", p),
+            Self::Bollinger(p) => format!("bollinger:{}", p),
+            Self::Rsi(p) => format!("rsi:{}", p),
+            Self::Macd { fast, slow, signal } => format!("macd:{}:{}:{}", fast, slow, signal),
+            Self::Atr(p) => format!("atr:{}", p),
+            Self::MaxDrawdown(p) => format!("max_drawdown:{}", p),
+            Self::CumulativeGain(p) => format!("cumulative_gain:{}", p),
+            Self::VolumeAvg(p) => format!("volume_avg:{}", p),
+            Self::Volatility(p) => format!("volatility:{}", p),
+            Self::DayChange => "day_change".to_string(),
+            Self::DayChangePercent => "day_change_percent".to_string(),
+            Self::Stochastic { period, smooth, signal } => format!("stochastic:{}:{}:{}", period, smooth, signal),
+            Self::WilliamsR(p) => format!("williams_r:{}", p),
+            Self::Dmi { di, adx } => format!("dmi:{}:{}", di, adx),
+            Self::Cci(p) => format!("cci:{}", p),
+            Self::Obv => "obv".to_string(),
+            Self::Vwap(p) => format!("vwap:{}", p),
+            Self::ChaikinMoneyFlow(p) => format!("cmf:{}", p),
+            Self::Mfi(p) => format!("mfi:{}", p),
+            Self::Keltner { period, multiplier, atr } => format!("keltner:{}:{}:{}", period, multiplier, atr),
+            Self::Donchian(p) => format!("donchian:{}", p),
+            Self::ParabolicSar { start, increment, maximum } => format!("psar:{}:{}:{}", start, increment, maximum),
+            Self::Ichimoku { conversion, base, span_b, displacement } => {
+                format!("ichimoku:{}:{}:{}:{}", conversion, base, span_b, displacement)
+            }
+            Self::Aroon(p) => format!("aroon:{}", p),
+        }
+    }
+
+    /// Calcule l'indicateur sur des points triés chronologiquement
+    pub fn series(&self, points: &[StockPoint]) -> IndicatorSeries {
+        let value = |values: Vec<Option<f64>>| BTreeMap::from([("value", values)]);
+        let channel = |c: indicators::ChannelSeries| BTreeMap::from([("upper", c.upper), ("middle", c.middle), ("lower", c.lower)]);
+        let lines = match *self {
+            Self::Sma(p) => value(indicators::sma_series(points, p)),
+            Self::Ema(p) => value(indicators::ema_series(points, p)),
+            Self::Bollinger(p) => {
+                let (upper, lower) = indicators::bollinger_series(points, p);
+                BTreeMap::from([("upper", upper), ("lower", lower)])
+            }
+            Self::Rsi(p) => value(indicators::rsi_series(points, p)),
+            Self::Macd { fast, slow, signal } => {
+                let macd = indicators::macd_lines(points, fast, slow, signal);
+                BTreeMap::from([("macd", macd.line), ("signal", macd.signal), ("histogram", macd.histogram)])
+            }
+            Self::Atr(p) => value(indicators::atr_series(points, p)),
+            Self::MaxDrawdown(p) => value(indicators::max_drawdown_series(points, p)),
+            Self::CumulativeGain(p) => value(indicators::cumulative_gain_series(points, p)),
+            Self::VolumeAvg(p) => value(indicators::volume_avg_series(points, p)),
+            Self::Volatility(p) => value(indicators::volatility_series(points, p)),
+            Self::DayChange => value(indicators::day_change_series(points)),
+            Self::DayChangePercent => value(indicators::day_change_percent_series(points)),
+            Self::Stochastic { period, smooth, signal } => {
+                let stochastic = indicators::stochastic_series(points, period, smooth, signal);
+                BTreeMap::from([("k", stochastic.k), ("d", stochastic.d)])
+            }
+            Self::WilliamsR(p) => value(indicators::williams_r_series(points, p)),
+            Self::Dmi { di, adx } => {
+                let dmi = indicators::dmi_series(points, di, adx);
+                BTreeMap::from([("plus_di", dmi.plus_di), ("minus_di", dmi.minus_di), ("adx", dmi.adx)])
+            }
+            Self::Cci(p) => value(indicators::cci_series(points, p)),
+            Self::Obv => value(indicators::obv_series(points)),
+            Self::Vwap(p) => value(indicators::vwap_series(points, p)),
+            Self::ChaikinMoneyFlow(p) => value(indicators::chaikin_money_flow_series(points, p)),
+            Self::Mfi(p) => value(indicators::mfi_series(points, p)),
+            Self::Keltner { period, multiplier, atr } => channel(indicators::keltner_series(points, period, multiplier, atr)),
+            Self::Donchian(p) => channel(indicators::donchian_series(points, p)),
+            Self::ParabolicSar { start, increment, maximum } => {
+                value(indicators::parabolic_sar_series(points, start, increment, maximum))
+            }
+            Self::Ichimoku { conversion, base, span_b, displacement } => {
+                let ichimoku = indicators::ichimoku_series(points, conversion, base, span_b, displacement);
+                BTreeMap::from([
+                    ("conversion", ichimoku.conversion),
+                    ("base", ichimoku.base),
+                    ("span_a", ichimoku.span_a),
+                    ("span_b", ichimoku.span_b),
+                    ("lagging", ichimoku.lagging),
+                ])
+            }
+            Self::Aroon(p) => {
+                let aroon = indicators::aroon_series(points, p);
+                BTreeMap::from([("up", aroon.up), ("down", aroon.down)])
+            }
+        };
+        IndicatorSeries { name: self.name(), lines }
+    }
+
+    /// Calcul incrémental : les points sont poussés un à un, sans reparcourir l'historique
+    pub fn stream(&self) -> Box<dyn StreamingIndicator> {
+        indicator_stream::for_spec(*self)
+    }
+}
+
+impl IndicatorChart {
+    pub fn build(points: &[StockPoint], specs: &[IndicatorSpec]) -> Self {
+        Self {
+            timestamps: points.iter().map(|p| p.timestamp).collect(),
+            indicators: specs.iter().map(|spec| spec.series(points)).collect(),
+        }
+    }
+}
@@ -0,0 +1,895 @@
+use std::collections::{BTreeMap, VecDeque};
+use crate::domain::indicator_series::IndicatorSpec;
+use crate::domain::indicators;
+use crate::domain::time_series::StockPoint;
+
+// Versions incrémentales des indicateurs de `indicators` : chaque point poussé met l'état à jour
+// en temps constant (amorti pour les extrêmes glissants) sans reparcourir l'historique, et les
+// valeurs courantes sont celles du dernier point de la série calculée d'un bloc.
+// Seuls le max drawdown et le CCI, qui dépendent de toute la fenêtre, coûtent O(period) par point.
+
+/// Indicateur tenu à jour point par point
+pub trait StreamingIndicator: Send {
+    /// Intègre le point suivant ; les points arrivent dans l'ordre chronologique
+    fn push(&mut self, point: &StockPoint);
+
+    /// Valeurs au dernier point, par courbe (mêmes noms que `IndicatorSpec::series`)
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>>;
+}
+
+fn value(value: Option<f64>) -> BTreeMap<&'static str, Option<f64>> {
+    BTreeMap::from([("value", value)])
+}
+
+fn typical_price(p: &StockPoint) -> f64 {
+    (p.high + p.low + p.close) / 3.0
+}
+
+// ---- Briques ----
+
+/// Somme glissante sur `period` valeurs, indéfinie tant que la fenêtre est incomplète ou
+/// contient une valeur indéfinie. Les sommes sont recalculées tous les `period` ajouts
+/// (coût amorti constant) pour ne pas accumuler d'erreurs d'arrondi.
+struct RollingSum {
+    period: usize,
+    values: VecDeque<Option<f64>>,
+    sum: f64,
+    /// Centre des écarts, proche de la moyenne pour limiter les annulations dans la variance
+    center: f64,
+    deviations: f64,
+    squares: f64,
+    missing: usize,
+    nonzero: usize,
+    since_refresh: usize,
+}
+
+impl RollingSum {
+    fn new(period: usize) -> Self {
+        Self {
+            period,
+            values: VecDeque::with_capacity(period + 1),
+            sum: 0.0,
+            center: 0.0,
+            deviations: 0.0,
+            squares: 0.0,
+            missing: 0,
+            nonzero: 0,
+            since_refresh: 0,
+        }
+    }
+
+    fn push(&mut self, value: Option<f64>) {
+        if self.period == 0 { return; }
+        self.account(value, 1.0);
+        self.values.push_back(value);
+        if self.values.len() > self.period {
+            let removed = self.values.pop_front().flatten();
+            self.account(removed, -1.0);
+        }
+        self.since_refresh += 1;
+        if self.since_refresh >= self.period || self.cancels() {
+            self.refresh();
+        }
+    }
+
+    /// Vrai quand l'écart du centre à la moyenne domine la dispersion : la variance perdrait
+    /// ses chiffres significatifs (fenêtre plate après un mouvement, par exemple)
+    fn cancels(&self) -> bool {
+        let count = (self.values.len() - self.missing) as f64;
+        if count == 0.0 { return false; }
+        let shift = self.deviations / count;
+        shift * shift > 1e8 * (self.squares / count - shift * shift).max(0.0)
+    }
+
+    fn account(&mut self, value: Option<f64>, sign: f64) {
+        match value {
+            Some(value) => {
+                let deviation = value - self.center;
+                self.sum += sign * value;
+                self.deviations += sign * deviation;
+                self.squares += sign * deviation * deviation;
+                if value != 0.0 {
+                    if sign > 0.0 { self.nonzero += 1 } else { self.nonzero -= 1 }
+                }
+            }
+            None if sign > 0.0 => self.missing += 1,
+            None => self.missing -= 1,
+        }
+    }
+
+    fn refresh(&mut self) {
+        let defined = self.values.iter().flatten();
+        let count = defined.clone().count();
+        self.sum = defined.clone().sum();
+        self.center = if count > 0 { self.sum / count as f64 } else { 0.0 };
+        self.deviations = defined.clone().map(|v| v - self.center).sum();
+        self.squares = defined.map(|v| (v - self.center).powi(2)).sum();
+        self.since_refresh = 0;
+    }
+
+    fn full(&self) -> bool {
+        self.period > 0 && self.values.len() == self.period && self.missing == 0
+    }
+
+    fn sum(&self) -> Option<f64> {
+        self.full().then_some(self.sum)
+    }
+
+    fn mean(&self) -> Option<f64> {
+        self.sum().map(|sum| sum / self.period as f64)
+    }
+
+    /// Écart-type de population
+    fn stddev(&self) -> Option<f64> {
+        if !self.full() { return None; }
+        let n = self.period as f64;
+        let shift = self.deviations / n;
+        Some((self.squares / n - shift * shift).max(0.0).sqrt())
+    }
+
+    /// Au moins une valeur non nulle dans la fenêtre
+    fn any_nonzero(&self) -> bool {
+        self.nonzero > 0
+    }
+}
+
+/// Version incrémentale de `smoothed` : v_t = α·x_t + (1 − α)·v_{t−1}, amorcée par la moyenne
+/// des `period` premières valeurs définies ; une entrée indéfinie donne une sortie indéfinie
+struct Smoother {
+    period: usize,
+    alpha: f64,
+    seed: f64,
+    seeded: usize,
+    state: Option<f64>,
+}
+
+impl Smoother {
+    fn new(period: usize, alpha: f64) -> Self {
+        Self { period, alpha, seed: 0.0, seeded: 0, state: None }
+    }
+
+    fn ema(period: usize) -> Self {
+        Self::new(period, 2.0 / (period as f64 + 1.0))
+    }
+
+    fn wilder(period: usize) -> Self {
+        Self::new(period, 1.0 / period as f64)
+    }
+
+    fn push(&mut self, value: Option<f64>) -> Option<f64> {
+        if self.period == 0 { return None; }
+        let value = value?;
+        self.state = match self.state {
+            Some(previous) => Some(self.alpha * value + (1.0 - self.alpha) * previous),
+            None => {
+                self.seed += value;
+                self.seeded += 1;
+                if self.seeded == self.period { Some(self.seed / self.period as f64) } else { None }
+            }
+        };
+        self.state
+    }
+}
+
+/// Plus haut (ou plus bas) glissant sur `period` valeurs, par file monotone (O(1) amorti).
+/// En cas d'égalité, la valeur la plus récente est retenue.
+struct Extreme {
+    period: usize,
+    highest: bool,
+    entries: VecDeque<(usize, f64)>,
+    count: usize,
+}
+
+impl Extreme {
+    fn new(period: usize, highest: bool) -> Self {
+        Self { period, highest, entries: VecDeque::new(), count: 0 }
+    }
+
+    fn push(&mut self, value: f64) {
+        while let Some(&(_, last)) = self.entries.back() {
+            let dominated = if self.highest { last <= value } else { last >= value };
+            if !dominated { break; }
+            self.entries.pop_back();
+        }
+        self.entries.push_back((self.count, value));
+        self.count += 1;
+        while self.entries.front().is_some_and(|&(index, _)| index + self.period < self.count) {
+            self.entries.pop_front();
+        }
+    }
+
+    fn full(&self) -> bool {
+        self.period > 0 && self.count >= self.period
+    }
+
+    fn value(&self) -> Option<f64> {
+        if !self.full() { return None; }
+        self.entries.front().map(|&(_, value)| value)
+    }
+
+    /// Points écoulés depuis l'extrême
+    fn age(&self) -> Option<usize> {
+        if !self.full() { return None; }
+        self.entries.front().map(|&(index, _)| self.count - 1 - index)
+    }
+}
+
+/// Plus haut des hauts et plus bas des bas sur `period` points
+struct Range {
+    highs: Extreme,
+    lows: Extreme,
+}
+
+impl Range {
+    fn new(period: usize) -> Self {
+        Self { highs: Extreme::new(period, true), lows: Extreme::new(period, false) }
+    }
+
+    fn push(&mut self, point: &StockPoint) {
+        self.highs.push(point.high);
+        self.lows.push(point.low);
+    }
+
+    fn bounds(&self) -> Option<(f64, f64)> {
+        Some((self.highs.value()?, self.lows.value()?))
+    }
+
+    fn midpoint(&self) -> Option<f64> {
+        self.bounds().map(|(high, low)| (high + low) / 2.0)
+    }
+}
+
+/// True range, haut − bas au premier point
+#[derive(Default)]
+struct TrueRange {
+    previous_close: Option<f64>,
+}
+
+impl TrueRange {
+    fn next(&mut self, p: &StockPoint) -> f64 {
+        let range = p.high - p.low;
+        let range = match self.previous_close {
+            Some(previous) => range.max((p.high - previous).abs()).max((p.low - previous).abs()),
+            None => range,
+        };
+        self.previous_close = Some(p.close);
+        range
+    }
+}
+
+// ---- Indicateurs ----
+
+/// Statistique d'une fenêtre glissante sur une grandeur du point (SMA, volume moyen, gain cumulé, volatilité)
+struct WindowStat {
+    window: RollingSum,
+    input: fn(&StockPoint) -> f64,
+    output: fn(&RollingSum) -> Option<f64>,
+}
+
+impl StreamingIndicator for WindowStat {
+    fn push(&mut self, point: &StockPoint) {
+        self.window.push(Some((self.input)(point)));
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        value((self.output)(&self.window))
+    }
+}
+
+/// Grandeur du seul dernier point (variation du jour)
+struct PointValue {
+    input: fn(&StockPoint) -> f64,
+    value: Option<f64>,
+}
+
+impl StreamingIndicator for PointValue {
+    fn push(&mut self, point: &StockPoint) {
+        self.value = Some((self.input)(point));
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        value(self.value)
+    }
+}
+
+/// Indicateur dépendant de toute la fenêtre, recalculé sur les `period` derniers points
+struct PointWindow {
+    period: usize,
+    points: VecDeque<StockPoint>,
+    compute: fn(&[StockPoint], usize) -> Vec<Option<f64>>,
+    value: Option<f64>,
+}
+
+impl StreamingIndicator for PointWindow {
+    fn push(&mut self, point: &StockPoint) {
+        self.points.push_back(point.clone());
+        if self.points.len() > self.period {
+            self.points.pop_front();
+        }
+        self.value = if self.period > 0 && self.points.len() == self.period {
+            (self.compute)(self.points.make_contiguous(), self.period).last().copied().flatten()
+        } else {
+            None
+        };
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        value(self.value)
+    }
+}
+
+struct Ema {
+    smoother: Smoother,
+    value: Option<f64>,
+}
+
+impl StreamingIndicator for Ema {
+    fn push(&mut self, point: &StockPoint) {
+        self.value = self.smoother.push(Some(point.close));
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        value(self.value)
+    }
+}
+
+struct Bollinger {
+    closes: RollingSum,
+}
+
+impl StreamingIndicator for Bollinger {
+    fn push(&mut self, point: &StockPoint) {
+        self.closes.push(Some(point.close));
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        let bands = self.closes.mean().zip(self.closes.stddev());
+        BTreeMap::from([
+            ("upper", bands.map(|(mean, stddev)| mean + 2.0 * stddev)),
+            ("lower", bands.map(|(mean, stddev)| mean - 2.0 * stddev)),
+        ])
+    }
+}
+
+struct Rsi {
+    previous_close: Option<f64>,
+    gains: Smoother,
+    losses: Smoother,
+    value: Option<f64>,
+}
+
+impl StreamingIndicator for Rsi {
+    fn push(&mut self, point: &StockPoint) {
+        let change = self.previous_close.map(|previous| point.close - previous);
+        self.previous_close = Some(point.close);
+        let gain = self.gains.push(change.map(|c| c.max(0.0)));
+        let loss = self.losses.push(change.map(|c| (-c).max(0.0)));
+        self.value = gain.zip(loss).map(|(gain, loss)| if loss == 0.0 { 100.0 } else { 100.0 - 100.0 / (1.0 + gain / loss) });
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        value(self.value)
+    }
+}
+
+struct Macd {
+    fast: Smoother,
+    slow: Smoother,
+    signal: Smoother,
+    line: Option<f64>,
+    signal_value: Option<f64>,
+}
+
+impl StreamingIndicator for Macd {
+    fn push(&mut self, point: &StockPoint) {
+        let fast = self.fast.push(Some(point.close));
+        let slow = self.slow.push(Some(point.close));
+        self.line = fast.zip(slow).map(|(fast, slow)| fast - slow);
+        self.signal_value = self.signal.push(self.line);
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        BTreeMap::from([
+            ("macd", self.line),
+            ("signal", self.signal_value),
+            ("histogram", self.line.zip(self.signal_value).map(|(line, signal)| line - signal)),
+        ])
+    }
+}
+
+struct Atr {
+    ranges: TrueRange,
+    smoother: Smoother,
+    value: Option<f64>,
+}
+
+impl StreamingIndicator for Atr {
+    fn push(&mut self, point: &StockPoint) {
+        let range = self.ranges.next(point);
+        self.value = self.smoother.push(Some(range));
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        value(self.value)
+    }
+}
+
+struct Stochastic {
+    range: Range,
+    k: RollingSum,
+    d: RollingSum,
+}
+
+impl StreamingIndicator for Stochastic {
+    fn push(&mut self, point: &StockPoint) {
+        self.range.push(point);
+        let raw = self
+            .range
+            .bounds()
+            .and_then(|(high, low)| (high > low).then(|| 100.0 * (point.close - low) / (high - low)));
+        self.k.push(raw);
+        self.d.push(self.k.mean());
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        BTreeMap::from([("k", self.k.mean()), ("d", self.d.mean())])
+    }
+}
+
+struct WilliamsR {
+    range: Range,
+    value: Option<f64>,
+}
+
+impl StreamingIndicator for WilliamsR {
+    fn push(&mut self, point: &StockPoint) {
+        self.range.push(point);
+        self.value = self
+            .range
+            .bounds()
+            .and_then(|(high, low)| (high > low).then(|| -100.0 * (high - point.close) / (high - low)));
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        value(self.value)
+    }
+}
+
+struct Dmi {
+    previous: Option<StockPoint>,
+    ranges: TrueRange,
+    range: Smoother,
+    plus: Smoother,
+    minus: Smoother,
+    adx: Smoother,
+    plus_di: Option<f64>,
+    minus_di: Option<f64>,
+    adx_value: Option<f64>,
+}
+
+impl StreamingIndicator for Dmi {
+    fn push(&mut self, point: &StockPoint) {
+        // Le premier point n'a ni mouvement directionnel ni true range retenu
+        let range = self.ranges.next(point);
+        let (range, plus, minus) = match &self.previous {
+            Some(previous) => {
+                let up = point.high - previous.high;
+                let down = previous.low - point.low;
+                (
+                    Some(range),
+                    Some(if up > down && up > 0.0 { up } else { 0.0 }),
+                    Some(if down > up && down > 0.0 { down } else { 0.0 }),
+                )
+            }
+            None => (None, None, None),
+        };
+        self.previous = Some(point.clone());
+
+        let range = self.range.push(range);
+        let directional = |movement: Option<f64>| match (movement, range) {
+            (Some(movement), Some(range)) if range > 0.0 => Some(100.0 * movement / range),
+            _ => None,
+        };
+        self.plus_di = directional(self.plus.push(plus));
+        self.minus_di = directional(self.minus.push(minus));
+        let dx = self.plus_di.zip(self.minus_di).map(|(plus, minus)| {
+            if plus + minus > 0.0 { 100.0 * (plus - minus).abs() / (plus + minus) } else { 0.0 }
+        });
+        self.adx_value = self.adx.push(dx);
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        BTreeMap::from([("plus_di", self.plus_di), ("minus_di", self.minus_di), ("adx", self.adx_value)])
+    }
+}
+
+struct Obv {
+    previous_close: Option<f64>,
+    obv: Option<f64>,
+}
+
+impl StreamingIndicator for Obv {
+    fn push(&mut self, point: &StockPoint) {
+        let mut obv = self.obv.unwrap_or(0.0);
+        if let Some(previous) = self.previous_close {
+            if point.close > previous {
+                obv += point.volume;
+            } else if point.close < previous {
+                obv -= point.volume;
+            }
+        }
+        self.previous_close = Some(point.close);
+        self.obv = Some(obv);
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        value(self.obv)
+    }
+}
+
+/// Rapport de deux sommes glissantes, défini si le volume de la fenêtre est non nul (VWAP, CMF)
+struct VolumeWeighted {
+    weighted: RollingSum,
+    volume: RollingSum,
+    input: fn(&StockPoint) -> f64,
+}
+
+impl StreamingIndicator for VolumeWeighted {
+    fn push(&mut self, point: &StockPoint) {
+        self.weighted.push(Some((self.input)(point)));
+        self.volume.push(Some(point.volume));
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        let volume = self.volume.sum().filter(|_| self.volume.any_nonzero());
+        value(self.weighted.sum().zip(volume).map(|(weighted, volume)| weighted / volume))
+    }
+}
+
+struct Mfi {
+    previous_price: Option<f64>,
+    positive: RollingSum,
+    negative: RollingSum,
+}
+
+impl StreamingIndicator for Mfi {
+    fn push(&mut self, point: &StockPoint) {
+        let price = typical_price(point);
+        if let Some(previous) = self.previous_price {
+            let flow = price * point.volume;
+            self.positive.push(Some(if price > previous { flow } else { 0.0 }));
+            self.negative.push(Some(if price < previous { flow } else { 0.0 }));
+        }
+        self.previous_price = Some(price);
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        let flows = self.positive.sum().zip(self.negative.sum());
+        value(flows.map(|(positive, negative)| {
+            if self.negative.any_nonzero() { 100.0 - 100.0 / (1.0 + positive / negative) } else { 100.0 }
+        }))
+    }
+}
+
+struct Keltner {
+    middle: Smoother,
+    ranges: TrueRange,
+    range: Smoother,
+    multiplier: f64,
+    middle_value: Option<f64>,
+    range_value: Option<f64>,
+}
+
+impl StreamingIndicator for Keltner {
+    fn push(&mut self, point: &StockPoint) {
+        self.middle_value = self.middle.push(Some(point.close));
+        let range = self.ranges.next(point);
+        self.range_value = self.range.push(Some(range));
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        let band = |sign: f64| self.middle_value.zip(self.range_value).map(|(m, r)| m + sign * self.multiplier * r);
+        BTreeMap::from([("upper", band(1.0)), ("middle", self.middle_value), ("lower", band(-1.0))])
+    }
+}
+
+struct Donchian {
+    range: Range,
+}
+
+impl StreamingIndicator for Donchian {
+    fn push(&mut self, point: &StockPoint) {
+        self.range.push(point);
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        let bounds = self.range.bounds();
+        BTreeMap::from([
+            ("upper", bounds.map(|b| b.0)),
+            ("middle", bounds.map(|b| (b.0 + b.1) / 2.0)),
+            ("lower", bounds.map(|b| b.1)),
+        ])
+    }
+}
+
+/// État du SAR parabolique après le deuxième point
+struct SarState {
+    rising: bool,
+    sar: f64,
+    extreme: f64,
+    factor: f64,
+}
+
+struct ParabolicSar {
+    start: f64,
+    increment: f64,
+    maximum: f64,
+    /// Avant-dernier et dernier points
+    previous: (Option<StockPoint>, Option<StockPoint>),
+    state: Option<SarState>,
+}
+
+impl StreamingIndicator for ParabolicSar {
+    fn push(&mut self, p: &StockPoint) {
+        match (&self.previous, &mut self.state) {
+            ((_, Some(last)), None) => {
+                let rising = p.close >= last.close;
+                let (sar, extreme) = if rising {
+                    (last.low.min(p.low), last.high.max(p.high))
+                } else {
+                    (last.high.max(p.high), last.low.min(p.low))
+                };
+                self.state = Some(SarState { rising, sar, extreme, factor: self.start });
+            }
+            ((Some(before), Some(last)), Some(state)) => {
+                state.sar += state.factor * (state.extreme - state.sar);
+                if state.rising {
+                    state.sar = state.sar.min(last.low).min(before.low);
+                    if p.low < state.sar {
+                        state.rising = false;
+                        state.sar = state.extreme.max(p.high);
+                        state.extreme = p.low;
+                        state.factor = self.start;
+                    } else if p.high > state.extreme {
+                        state.extreme = p.high;
+                        state.factor = (state.factor + self.increment).min(self.maximum);
+                    }
+                } else {
+                    state.sar = state.sar.max(last.high).max(before.high);
+                    if p.high > state.sar {
+                        state.rising = true;
+                        state.sar = state.extreme.min(p.low);
+                        state.extreme = p.high;
+                        state.factor = self.start;
+                    } else if p.low < state.extreme {
+                        state.extreme = p.low;
+                        state.factor = (state.factor + self.increment).min(self.maximum);
+                    }
+                }
+            }
+            _ => {}
+        }
+        self.previous = (self.previous.1.take(), Some(p.clone()));
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        value(self.state.as_ref().map(|state| state.sar))
+    }
+}
+
+struct Ichimoku {
+    conversion: Range,
+    base: Range,
+    span_b: Range,
+    shift: usize,
+    /// Lignes avancées (A, B) des `shift + 1` derniers points
+    leads: VecDeque<(Option<f64>, Option<f64>)>,
+    last_close: Option<f64>,
+}
+
+impl StreamingIndicator for Ichimoku {
+    fn push(&mut self, point: &StockPoint) {
+        self.conversion.push(point);
+        self.base.push(point);
+        self.span_b.push(point);
+        let lead_a = self.conversion.midpoint().zip(self.base.midpoint()).map(|(c, b)| (c + b) / 2.0);
+        self.leads.push_back((lead_a, self.span_b.midpoint()));
+        if self.leads.len() > self.shift + 1 {
+            self.leads.pop_front();
+        }
+        self.last_close = Some(point.close);
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        let (span_a, span_b) = match self.leads.front() {
+            Some(&leads) if self.leads.len() == self.shift + 1 => leads,
+            _ => (None, None),
+        };
+        BTreeMap::from([
+            ("conversion", self.conversion.midpoint()),
+            ("base", self.base.midpoint()),
+            ("span_a", span_a),
+            ("span_b", span_b),
+            // La clôture retardée n'est connue au dernier point que sans décalage
+            ("lagging", self.last_close.filter(|_| self.shift == 0)),
+        ])
+    }
+}
+
+struct Aroon {
+    period: usize,
+    highs: Extreme,
+    lows: Extreme,
+}
+
+impl StreamingIndicator for Aroon {
+    fn push(&mut self, point: &StockPoint) {
+        self.highs.push(point.high);
+        self.lows.push(point.low);
+    }
+
+    fn current(&self) -> BTreeMap<&'static str, Option<f64>> {
+        let score = |age: Option<usize>| {
+            age.filter(|_| self.period > 0).map(|since| 100.0 * (self.period - since) as f64 / self.period as f64)
+        };
+        BTreeMap::from([("up", score(self.highs.age())), ("down", score(self.lows.age()))])
+    }
+}
+
+/// Version incrémentale d'un indicateur, vide tant qu'aucun point n'a été poussé
+pub fn for_spec(spec: IndicatorSpec) -> Box<dyn StreamingIndicator> {
+    let window = |period: usize, input: fn(&StockPoint) -> f64, output: fn(&RollingSum) -> Option<f64>| {
+        Box::new(WindowStat { window: RollingSum::new(period), input, output }) as Box<dyn StreamingIndicator>
+    };
+    let point_window = |period: usize, compute: fn(&[StockPoint], usize) -> Vec<Option<f64>>| {
+        Box::new(PointWindow { period, points: VecDeque::with_capacity(period + 1), compute, value: None }) as Box<dyn StreamingIndicator>
+    };
+    let volume_weighted = |period: usize, input: fn(&StockPoint) -> f64| {
+        Box::new(VolumeWeighted { weighted: RollingSum::new(period), volume: RollingSum::new(period), input }) as Box<dyn StreamingIndicator>
+    };
+
+    match spec {
+        IndicatorSpec::Sma(p) => window(p, |p| p.close, RollingSum::mean),
+        IndicatorSpec::Ema(p) => Box::new(Ema { smoother: Smoother::ema(p), value: None }),
+        IndicatorSpec::Bollinger(p) => Box::new(Bollinger { closes: RollingSum::new(p) }),
+        IndicatorSpec::Rsi(p) => Box::new(Rsi {
+            previous_close: None,
+            gains: Smoother::wilder(p),
+            losses: Smoother::wilder(p),
+            value: None,
+        }),
+        IndicatorSpec::Macd { fast, slow, signal } => Box::new(Macd {
+            fast: Smoother::ema(fast),
+            slow: Smoother::ema(slow),
+            signal: Smoother::ema(signal),
+            line: None,
+            signal_value: None,
+        }),
+        IndicatorSpec::Atr(p) => Box::new(Atr { ranges: TrueRange::default(), smoother: Smoother::wilder(p), value: None }),
+        IndicatorSpec::MaxDrawdown(p) => point_window(p, indicators::max_drawdown_series),
+        IndicatorSpec::CumulativeGain(p) => window(p, |p| p.close - p.open, RollingSum::sum),
+        IndicatorSpec::VolumeAvg(p) => window(p, |p| p.volume, RollingSum::mean),
+        IndicatorSpec::Volatility(p) => window(p, |p| p.close, RollingSum::stddev),
+        IndicatorSpec::DayChange => Box::new(PointValue { input: |p| p.close - p.open, value: None }),
+        IndicatorSpec::DayChangePercent => Box::new(PointValue { input: |p| (p.close - p.open) / p.open * 100.0, value: None }),
+        IndicatorSpec::Stochastic { period, smooth, signal } => Box::new(Stochastic {
+            range: Range::new(period),
+            k: RollingSum::new(smooth),
+            d: RollingSum::new(signal),
+        }),
+        IndicatorSpec::WilliamsR(p) => Box::new(WilliamsR { range: Range::new(p), value: None }),
+        IndicatorSpec::Dmi { di, adx } => Box::new(Dmi {
+            previous: None,
+            ranges: TrueRange::default(),
+            range: Smoother::wilder(di),
+            plus: Smoother::wilder(di),
+            minus: Smoother::wilder(di),
+            adx: Smoother::wilder(adx),
+            plus_di: None,
+            minus_di: None,
+            adx_value: None,
+        }),
+        IndicatorSpec::Cci(p) => point_window(p, indicators::cci_series),
+        IndicatorSpec::Obv => Box::new(Obv { previous_close: None, obv: None }),
+        IndicatorSpec::Vwap(p) => volume_weighted(p, |p| typical_price(p) * p.volume),
+        IndicatorSpec::ChaikinMoneyFlow(p) => volume_weighted(p, |p| {
+            let range = p.high - p.low;
+            if range > 0.0 { ((p.close - p.low) - (p.high - p.close)) / range * p.volume } else { 0.0 }
+        }),
+        IndicatorSpec::Mfi(p) => Box::new(Mfi { previous_price: None, positive: RollingSum::new(p), negative: RollingSum::new(p) }),
+        IndicatorSpec::Keltner { period, multiplier, atr } => Box::new(Keltner {
+            middle: Smoother::ema(period),
+            ranges: TrueRange::default(),
+            range: Smoother::wilder(atr),
+            multiplier,
+            middle_value: None,
+            range_value: None,
+        }),
+        IndicatorSpec::Donchian(p) => Box::new(Donchian { range: Range::new(p) }),
+        IndicatorSpec::ParabolicSar { start, increment, maximum } => Box::new(ParabolicSar {
+            start,
+            increment,
+            maximum,
+            previous: (None, None),
+            state: None,
+        }),
+        IndicatorSpec::Ichimoku { conversion, base, span_b, displacement } => Box::new(Ichimoku {
+            conversion: Range::new(conversion),
+            base: Range::new(base),
+            span_b: Range::new(span_b),
+            shift: displacement.saturating_sub(1),
+            leads: VecDeque::new(),
+            last_close: None,
+        }),
+        IndicatorSpec::Aroon(p) => Box::new(Aroon {
+            period: p,
+            highs: Extreme::new(p + 1, true),
+            lows: Extreme::new(p + 1, false),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration};
+
+    /// Bougies quotidiennes déterministes, avec des plages plates (haut = bas = clôture)
+    /// et des volumes nuls pour exercer les cas dégénérés
+    fn fixture() -> Vec<StockPoint> {
+        let start = DateTime::from_timestamp(1_577_836_800, 0).unwrap();
+        let mut close = 100.0;
+        (0..600)
+            .map(|i| {
+                let x = i as f64;
+                let flat = (i / 40) % 7 == 3;
+                if !flat {
+                    close *= 1.0 + 0.02 * (x * 0.37).sin() + 0.01 * (x * 1.13 + 0.5).cos();
+                }
+                let open = if flat { close } else { close * (1.0 + 0.008 * (x * 2.71).sin()) };
+                let high = if flat { close } else { close.max(open) * (1.0 + 0.005 * (1.0 + (x * 0.61).sin())) };
+                let low = if flat { close } else { close.min(open) * (1.0 - 0.005 * (1.0 + (x * 0.83).cos())) };
+                let volume = if i % 50 < 12 { 0.0 } else { (100 + (i * 37) % 900) as f64 };
+                StockPoint { timestamp: start + Duration::days(i as i64), open, high, low, close, volume }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn streams_match_batch_series() {
+        let points = fixture();
+        let specs = [
+            "sma:20", "sma:1", "ema:12", "ema:1", "bollinger:20", "rsi:14", "rsi:1", "macd:12:26:9", "atr:14",
+            "max_drawdown:30", "cumulative_gain:30", "volume_avg:30", "volatility:30", "day_change",
+            "day_change_percent", "stochastic:14:3:3", "stochastic:5", "williams_r:14", "dmi:14:10", "cci:20", "obv",
+            "vwap:20", "cmf:20", "mfi:14", "keltner:20:2:10", "donchian:20", "psar", "ichimoku", "ichimoku:9:26:52:1",
+            "aroon:14", "aroon:1",
+        ];
+        for raw in specs {
+            let spec = IndicatorSpec::parse(raw).unwrap();
+            let batch = spec.series(&points);
+            let mut stream = spec.stream();
+            for (i, point) in points.iter().enumerate() {
+                stream.push(point);
+                let current = stream.current();
+                // La ligne retardée d'Ichimoku lit des points futurs : la référence est la série
+                // calculée sur les seuls points déjà connus
+                let expected = match spec {
+                    IndicatorSpec::Ichimoku { .. } => spec.series(&points[..=i]),
+                    _ => batch.clone(),
+                };
+                let at = |values: &Vec<Option<f64>>| if values.len() == points.len() { values[i] } else { values[values.len() - 1] };
+                assert_eq!(current.keys().collect::<Vec<_>>(), expected.lines.keys().collect::<Vec<_>>(), "{}", raw);
+                for (line, values) in &expected.lines {
+                    match (at(values), current[line]) {
+                        (None, None) => {}
+                        (Some(a), Some(b)) => {
+                            assert!((a - b).abs() <= 1e-7 * a.abs().max(1.0), "{} {} au point {} : {} / {}", raw, line, i, a, b)
+                        }
+                        (a, b) => panic!("{} {} au point {} : {:?} / {:?}", raw, line, i, a, b),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,96 +1,748 @@
 use crate::domain::time_series::StockPoint;
 
-pub fn last_close(points: &[StockPoint]) -> f64 {
-    points.last().map(|p| p.close).unwrap_or(0.0)
+// Chaque indicateur est une série alignée sur les points : les indicateurs de fenêtre glissent
+// sur les `period` derniers points, `None` tant que la fenêtre est incomplète.
+// Les indicateurs récursifs (EMA, RSI, ATR, MACD) suivent les définitions de référence
+// (Wilder, Appel) avec l'amorçage de TradingView et parcourent tout l'historique.
+
+/// Applique `f` à la fenêtre se terminant à chaque point ; `None` tant qu'elle est incomplète
+fn rolling<T>(points: &[StockPoint], period: usize, f: impl Fn(&[StockPoint]) -> T) -> Vec<Option<T>> {
+    (0..points.len())
+        .map(|i| if period == 0 || i + 1 < period { None } else { Some(f(&points[i + 1 - period..=i])) })
+        .collect()
+}
+
+/// Moyenne mobile récursive v_t = α·x_t + (1 − α)·v_{t−1}, amorcée par la moyenne simple
+/// des `period` premières valeurs définies (α = 2/(n+1) pour une EMA, 1/n pour le lissage de Wilder)
+fn smoothed(values: &[Option<f64>], period: usize, alpha: f64) -> Vec<Option<f64>> {
+    let mut smoothed = vec![None; values.len()];
+    if period == 0 { return smoothed; }
+    let (mut sum, mut count) = (0.0, 0);
+    let mut previous: Option<f64> = None;
+    for (i, value) in values.iter().enumerate() {
+        let Some(value) = *value else { continue };
+        previous = match previous {
+            Some(previous) => Some(alpha * value + (1.0 - alpha) * previous),
+            None => {
+                sum += value;
+                count += 1;
+                if count == period { Some(sum / period as f64) } else { None }
+            }
+        };
+        smoothed[i] = previous;
+    }
+    smoothed
+}
+
+fn closes(points: &[StockPoint]) -> Vec<Option<f64>> {
+    points.iter().map(|p| Some(p.close)).collect()
+}
+
+pub fn day_change_series(points: &[StockPoint]) -> Vec<Option<f64>> {
+    points.iter().map(|p| Some(p.close - p.open)).collect()
+}
+
+pub fn day_change_percent_series(points: &[StockPoint]) -> Vec<Option<f64>> {
+    points.iter().map(|p| Some((p.close - p.open) / p.open * 100.0)).collect()
+}
+
+fn sma_window(window: &[StockPoint]) -> f64 {
+    window.iter().map(|p| p.close).sum::<f64>() / window.len() as f64
+}
+
+pub fn sma_series(points: &[StockPoint], period: usize) -> Vec<Option<f64>> {
+    rolling(points, period, sma_window)
+}
+
+/// EMA de pas α = 2/(period+1), amorcée par la SMA des `period` premières clôtures
+pub fn ema_series(points: &[StockPoint], period: usize) -> Vec<Option<f64>> {
+    smoothed(&closes(points), period, 2.0 / (period as f64 + 1.0))
+}
+
+/// Bandes (haute, basse) à deux écarts-types de la moyenne
+fn bollinger_window(window: &[StockPoint]) -> (f64, f64) {
+    let mean = sma_window(window);
+    let stddev = volatility_window(window);
+    (mean + 2.0*stddev, mean - 2.0*stddev)
+}
+
+pub fn bollinger_series(points: &[StockPoint], period: usize) -> (Vec<Option<f64>>, Vec<Option<f64>>) {
+    rolling(points, period, bollinger_window)
+        .into_iter()
+        .map(|bands| (bands.map(|b| b.0), bands.map(|b| b.1)))
+        .unzip()
+}
+
+/// RSI de Wilder : variations de clôture à clôture, gains et pertes moyens lissés en 1/period.
+/// Première valeur au point `period` (il faut `period` variations).
+pub fn rsi_series(points: &[StockPoint], period: usize) -> Vec<Option<f64>> {
+    let changes: Vec<Option<f64>> = (0..points.len())
+        .map(|i| if i == 0 { None } else { Some(points[i].close - points[i - 1].close) })
+        .collect();
+    let alpha = 1.0 / period as f64;
+    let gains = smoothed(&changes.iter().map(|c| c.map(|c| c.max(0.0))).collect::<Vec<_>>(), period, alpha);
+    let losses = smoothed(&changes.iter().map(|c| c.map(|c| (-c).max(0.0))).collect::<Vec<_>>(), period, alpha);
+
+    gains
+        .into_iter()
+        .zip(losses)
+        .map(|(gain, loss)| {
+            let (gain, loss) = (gain?, loss?);
+            Some(if loss == 0.0 { 100.0 } else { 100.0 - 100.0 / (1.0 + gain / loss) })
+        })
+        .collect()
+}
+
+/// Séries du MACD, alignées sur les points
+pub struct MacdSeries {
+    /// EMA(fast) − EMA(slow)
+    pub line: Vec<Option<f64>>,
+    /// EMA(signal) de la ligne
+    pub signal: Vec<Option<f64>>,
+    /// Ligne − signal
+    pub histogram: Vec<Option<f64>>,
+}
+
+/// MACD d'Appel pour des périodes quelconques
+pub fn macd_lines(points: &[StockPoint], fast: usize, slow: usize, signal: usize) -> MacdSeries {
+    let line: Vec<Option<f64>> = ema_series(points, fast)
+        .into_iter()
+        .zip(ema_series(points, slow))
+        .map(|(fast, slow)| Some(fast? - slow?))
+        .collect();
+    let signal = smoothed(&line, signal, 2.0 / (signal as f64 + 1.0));
+    let histogram = line.iter().zip(&signal).map(|(line, signal)| Some((*line)? - (*signal)?)).collect();
+    MacdSeries { line, signal, histogram }
+}
+
+/// True range : plus grand écart entre haut, bas et clôture précédente (haut − bas au premier point)
+fn true_ranges(points: &[StockPoint]) -> Vec<Option<f64>> {
+    (0..points.len())
+        .map(|i| {
+            let p = &points[i];
+            let range = p.high - p.low;
+            Some(match i.checked_sub(1).map(|j| points[j].close) {
+                Some(previous) => range.max((p.high - previous).abs()).max((p.low - previous).abs()),
+                None => range,
+            })
+        })
+        .collect()
+}
+
+/// ATR de Wilder : true range lissé en 1/period, amorcé par la moyenne des `period` premiers
+pub fn atr_series(points: &[StockPoint], period: usize) -> Vec<Option<f64>> {
+    smoothed(&true_ranges(points), period, 1.0 / period as f64)
+}
+
+/// Plus forte baisse d'un plus haut vers un plus bas ultérieur (négative ou nulle, en prix)
+fn max_drawdown_window(window: &[StockPoint]) -> f64 {
+    let mut peak = f64::MIN;
+    let mut drawdown: f64 = 0.0;
+    for close in window.iter().map(|p| p.close) {
+        peak = peak.max(close);
+        drawdown = drawdown.min(close - peak);
+    }
+    drawdown
+}
+
+pub fn max_drawdown_series(points: &[StockPoint], period: usize) -> Vec<Option<f64>> {
+    rolling(points, period, max_drawdown_window)
+}
+
+fn cumulative_gain_window(window: &[StockPoint]) -> f64 {
+    window.iter().map(|p| p.close - p.open).sum()
+}
+
+pub fn cumulative_gain_series(points: &[StockPoint], period: usize) -> Vec<Option<f64>> {
+    rolling(points, period, cumulative_gain_window)
+}
+
+fn volume_avg_window(window: &[StockPoint]) -> f64 {
+    window.iter().map(|p| p.volume).sum::<f64>() / window.len() as f64
+}
+
+pub fn volume_avg_series(points: &[StockPoint], period: usize) -> Vec<Option<f64>> {
+    rolling(points, period, volume_avg_window)
+}
+
+fn volatility_window(window: &[StockPoint]) -> f64 {
+    let mean = sma_window(window);
+    let variance = window.iter().map(|p| (p.close - mean).powi(2)).sum::<f64>() / window.len() as f64;
+    variance.sqrt()
+}
+
+pub fn volatility_series(points: &[StockPoint], period: usize) -> Vec<Option<f64>> {
+    rolling(points, period, volatility_window)
+}
+
+// ---- Oscillateurs, volumes et canaux ----
+
+fn highest_high(window: &[StockPoint]) -> f64 {
+    window.iter().map(|p| p.high).fold(f64::MIN, f64::max)
+}
+
+fn lowest_low(window: &[StockPoint]) -> f64 {
+    window.iter().map(|p| p.low).fold(f64::MAX, f64::min)
 }
 
-pub fn day_change(points: &[StockPoint]) -> f64 {
-    points.last().map(|p| p.close - p.open).unwrap_or(0.0)
+fn typical_price(p: &StockPoint) -> f64 {
+    (p.high + p.low + p.close) / 3.0
 }
 
-pub fn day_change_percent(points: &[StockPoint]) -> Option<f64> {
-    points.last().map(|p| (p.close - p.open) / p.open * 100.0)
+/// Moyenne simple glissante d'une série pouvant comporter des valeurs indéfinies
+fn rolling_mean(values: &[Option<f64>], period: usize) -> Vec<Option<f64>> {
+    (0..values.len())
+        .map(|i| {
+            if period == 0 || i + 1 < period { return None; }
+            values[i + 1 - period..=i].iter().copied().sum::<Option<f64>>().map(|sum| sum / period as f64)
+        })
+        .collect()
 }
 
-pub fn sma(points: &[StockPoint], period: usize) -> Option<f64> {
-    if points.len() < period { return None; }
-    let sum: f64 = points.iter().rev().take(period).map(|p| p.close).sum();
-    Some(sum / period as f64)
+/// Stochastique : %K = 100·(C − plus bas)/(plus haut − plus bas) sur `period`, lissé en SMA(`smooth`) ;
+/// %D = SMA(`signal`) de %K. Indéfini sur une fenêtre sans amplitude.
+pub struct StochasticSeries {
+    pub k: Vec<Option<f64>>,
+    pub d: Vec<Option<f64>>,
 }
 
-pub fn ema(points: &[StockPoint], period: usize) -> Option<f64> {
-    if points.len() < period { return None; }
-    let k = 2.0 / (period as f64 + 1.0);
-    let mut ema_prev = points.iter().rev().take(period).last()?.close;
-    for p in points.iter().rev().take(period).skip(1) {
-        ema_prev = p.close * k + ema_prev * (1.0 - k);
+pub fn stochastic_series(points: &[StockPoint], period: usize, smooth: usize, signal: usize) -> StochasticSeries {
+    let raw: Vec<Option<f64>> = rolling(points, period, |w| {
+        let (high, low) = (highest_high(w), lowest_low(w));
+        let close = w[w.len() - 1].close;
+        if high > low { Some(100.0 * (close - low) / (high - low)) } else { None }
+    })
+    .into_iter()
+    .map(Option::flatten)
+    .collect();
+    let k = rolling_mean(&raw, smooth);
+    let d = rolling_mean(&k, signal);
+    StochasticSeries { k, d }
+}
+
+/// Williams %R : −100·(plus haut − C)/(plus haut − plus bas) sur `period`, entre −100 et 0
+pub fn williams_r_series(points: &[StockPoint], period: usize) -> Vec<Option<f64>> {
+    rolling(points, period, |w| {
+        let (high, low) = (highest_high(w), lowest_low(w));
+        let close = w[w.len() - 1].close;
+        if high > low { Some(-100.0 * (high - close) / (high - low)) } else { None }
+    })
+    .into_iter()
+    .map(Option::flatten)
+    .collect()
+}
+
+/// DMI / ADX de Wilder : indicateurs directionnels +DI et −DI sur `di_period`,
+/// ADX = moyenne lissée sur `adx_period` de DX = 100·|+DI − −DI|/(+DI + −DI)
+pub struct DmiSeries {
+    pub plus_di: Vec<Option<f64>>,
+    pub minus_di: Vec<Option<f64>>,
+    pub adx: Vec<Option<f64>>,
+}
+
+pub fn dmi_series(points: &[StockPoint], di_period: usize, adx_period: usize) -> DmiSeries {
+    let n = points.len();
+    let (mut plus_dm, mut minus_dm) = (vec![None; n], vec![None; n]);
+    for i in 1..n {
+        let up = points[i].high - points[i - 1].high;
+        let down = points[i - 1].low - points[i].low;
+        plus_dm[i] = Some(if up > down && up > 0.0 { up } else { 0.0 });
+        minus_dm[i] = Some(if down > up && down > 0.0 { down } else { 0.0 });
+    }
+    // Le premier true range n'a pas de clôture précédente : il est écarté comme les mouvements directionnels
+    let mut ranges = true_ranges(points);
+    if let Some(first) = ranges.first_mut() {
+        *first = None;
     }
-    Some(ema_prev)
+
+    let alpha = 1.0 / di_period as f64;
+    let range = smoothed(&ranges, di_period, alpha);
+    let directional = |movement: &[Option<f64>]| -> Vec<Option<f64>> {
+        smoothed(movement, di_period, alpha)
+            .into_iter()
+            .zip(&range)
+            .map(|(movement, range)| match (movement, *range) {
+                (Some(movement), Some(range)) if range > 0.0 => Some(100.0 * movement / range),
+                _ => None,
+            })
+            .collect()
+    };
+    let plus_di = directional(&plus_dm);
+    let minus_di = directional(&minus_dm);
+
+    let dx: Vec<Option<f64>> = plus_di
+        .iter()
+        .zip(&minus_di)
+        .map(|(plus, minus)| {
+            let (plus, minus) = ((*plus)?, (*minus)?);
+            Some(if plus + minus > 0.0 { 100.0 * (plus - minus).abs() / (plus + minus) } else { 0.0 })
+        })
+        .collect();
+    let adx = smoothed(&dx, adx_period, 1.0 / adx_period as f64);
+    DmiSeries { plus_di, minus_di, adx }
+}
+
+/// CCI de Lambert : (TP − SMA(TP))/(0.015 · écart absolu moyen), TP = (H + B + C)/3
+pub fn cci_series(points: &[StockPoint], period: usize) -> Vec<Option<f64>> {
+    rolling(points, period, |w| {
+        let prices: Vec<f64> = w.iter().map(typical_price).collect();
+        let mean = prices.iter().sum::<f64>() / prices.len() as f64;
+        let deviation = prices.iter().map(|p| (p - mean).abs()).sum::<f64>() / prices.len() as f64;
+        if deviation > 0.0 { Some((prices[prices.len() - 1] - mean) / (0.015 * deviation)) } else { None }
+    })
+    .into_iter()
+    .map(Option::flatten)
+    .collect()
+}
+
+/// On-balance volume : cumul du volume, ajouté en hausse et retranché en baisse (0 au premier point)
+pub fn obv_series(points: &[StockPoint]) -> Vec<Option<f64>> {
+    let mut obv = 0.0;
+    (0..points.len())
+        .map(|i| {
+            if i > 0 {
+                let (previous, current) = (points[i - 1].close, points[i].close);
+                if current > previous {
+                    obv += points[i].volume;
+                } else if current < previous {
+                    obv -= points[i].volume;
+                }
+            }
+            Some(obv)
+        })
+        .collect()
+}
+
+/// VWAP glissant : moyenne des prix typiques pondérée par le volume sur `period` points
+pub fn vwap_series(points: &[StockPoint], period: usize) -> Vec<Option<f64>> {
+    rolling(points, period, |w| {
+        let volume: f64 = w.iter().map(|p| p.volume).sum();
+        if volume > 0.0 { Some(w.iter().map(|p| typical_price(p) * p.volume).sum::<f64>() / volume) } else { None }
+    })
+    .into_iter()
+    .map(Option::flatten)
+    .collect()
 }
 
-pub fn bollinger(points: &[StockPoint], period: usize) -> (Option<f64>, Option<f64>) {
-    if points.len() < period { return (None, None); }
-    let slice = points.iter().rev().take(period).collect::<Vec<_>>();
-    let mean = slice.iter().map(|p| p.close).sum::<f64>() / period as f64;
-    let variance = slice.iter().map(|p| (p.close - mean).powi(2)).sum::<f64>() / period as f64;
-    let stddev = variance.sqrt();
-    (Some(mean + 2.0*stddev), Some(mean - 2.0*stddev))
+/// Chaikin money flow : somme des volumes pondérés par ((C − B) − (H − C))/(H − B), rapportée au volume
+pub fn chaikin_money_flow_series(points: &[StockPoint], period: usize) -> Vec<Option<f64>> {
+    rolling(points, period, |w| {
+        let volume: f64 = w.iter().map(|p| p.volume).sum();
+        let flow: f64 = w
+            .iter()
+            .map(|p| {
+                let range = p.high - p.low;
+                if range > 0.0 { ((p.close - p.low) - (p.high - p.close)) / range * p.volume } else { 0.0 }
+            })
+            .sum();
+        if volume > 0.0 { Some(flow / volume) } else { None }
+    })
+    .into_iter()
+    .map(Option::flatten)
+    .collect()
 }
 
-pub fn rsi(points: &[StockPoint], period: usize) -> Option<f64> {
-    if points.len() < period { return None; }
-    let slice = points.iter().rev().take(period);
-    let gains: f64 = slice.clone().map(|p| (p.close - p.open).max(0.0)).sum();
-    let losses: f64 = slice.map(|p| (p.open - p.close).max(0.0)).sum();
-    if losses == 0.0 { Some(100.0) } else { Some(100.0 - 100.0 / (1.0 + gains / losses)) }
+/// Money flow index : RSI des flux TP·volume, positifs quand le prix typique monte, sur `period` variations
+pub fn mfi_series(points: &[StockPoint], period: usize) -> Vec<Option<f64>> {
+    if period == 0 { return vec![None; points.len()]; }
+    rolling(points, period + 1, |w| {
+        let (mut positive, mut negative) = (0.0, 0.0);
+        for pair in w.windows(2) {
+            let (previous, current) = (typical_price(&pair[0]), typical_price(&pair[1]));
+            if current > previous {
+                positive += current * pair[1].volume;
+            } else if current < previous {
+                negative += current * pair[1].volume;
+            }
+        }
+        if negative > 0.0 { 100.0 - 100.0 / (1.0 + positive / negative) } else { 100.0 }
+    })
 }
 
-pub fn macd(points: &[StockPoint]) -> Option<f64> {
-    let ema7 = ema(points, 7)?;
-    let ema30 = ema(points, 30)?;
-    Some(ema7 - ema30)
+/// Canal (haut, milieu, bas) aligné sur les points
+pub struct ChannelSeries {
+    pub upper: Vec<Option<f64>>,
+    pub middle: Vec<Option<f64>>,
+    pub lower: Vec<Option<f64>>,
 }
 
-pub fn atr(points: &[StockPoint], period: usize) -> Option<f64> {
-    if points.len() < period { return None; }
-    let slice = points.iter().rev().take(period).collect::<Vec<_>>();
-    let tr_sum: f64 = slice.iter()
-        .map(|p| (p.high - p.low).abs())
-        .sum();
-    Some(tr_sum / period as f64)
+/// Canal de Keltner : EMA(`period`) ± `multiplier` · ATR(`atr_period`)
+pub fn keltner_series(points: &[StockPoint], period: usize, multiplier: f64, atr_period: usize) -> ChannelSeries {
+    let middle = ema_series(points, period);
+    let range = atr_series(points, atr_period);
+    let band = |sign: f64| -> Vec<Option<f64>> {
+        middle.iter().zip(&range).map(|(m, r)| Some((*m)? + sign * multiplier * (*r)?)).collect()
+    };
+    ChannelSeries { upper: band(1.0), lower: band(-1.0), middle }
+}
+
+/// Canal de Donchian : plus haut et plus bas sur `period`, et leur milieu
+pub fn donchian_series(points: &[StockPoint], period: usize) -> ChannelSeries {
+    let bounds = rolling(points, period, |w| (highest_high(w), lowest_low(w)));
+    ChannelSeries {
+        upper: bounds.iter().map(|b| b.map(|b| b.0)).collect(),
+        middle: bounds.iter().map(|b| b.map(|b| (b.0 + b.1) / 2.0)).collect(),
+        lower: bounds.iter().map(|b| b.map(|b| b.1)).collect(),
+    }
+}
+
+/// Parabolic SAR de Wilder : facteur d'accélération `start`, augmenté de `increment` à chaque
+/// nouvel extrême et plafonné à `maximum`. Le sens initial suit la variation des deux premières clôtures.
+pub fn parabolic_sar_series(points: &[StockPoint], start: f64, increment: f64, maximum: f64) -> Vec<Option<f64>> {
+    let mut sar_values = vec![None; points.len()];
+    if points.len() < 2 { return sar_values; }
+
+    let mut rising = points[1].close >= points[0].close;
+    let (mut sar, mut extreme) = if rising {
+        (points[0].low.min(points[1].low), points[0].high.max(points[1].high))
+    } else {
+        (points[0].high.max(points[1].high), points[0].low.min(points[1].low))
+    };
+    let mut factor = start;
+    sar_values[1] = Some(sar);
+
+    for i in 2..points.len() {
+        let p = &points[i];
+        sar += factor * (extreme - sar);
+        if rising {
+            // Le SAR ne peut dépasser les plus bas des deux points précédents
+            sar = sar.min(points[i - 1].low).min(points[i - 2].low);
+            if p.low < sar {
+                // Un point qui crève le SAR peut aussi avoir prolongé l'extrême
+                rising = false;
+                sar = extreme.max(p.high);
+                extreme = p.low;
+                factor = start;
+            } else if p.high > extreme {
+                extreme = p.high;
+                factor = (factor + increment).min(maximum);
+            }
+        } else {
+            sar = sar.max(points[i - 1].high).max(points[i - 2].high);
+            if p.high > sar {
+                rising = true;
+                sar = extreme.min(p.low);
+                extreme = p.high;
+                factor = start;
+            } else if p.low < extreme {
+                extreme = p.low;
+                factor = (factor + increment).min(maximum);
+            }
+        }
+        sar_values[i] = Some(sar);
+    }
+    sar_values
 }
 
-pub fn max_drawdown(points: &[StockPoint], period: usize) -> Option<f64> {
-    if points.len() < period { return None; }
-    let slice = points.iter().rev().take(period).map(|p| p.close).collect::<Vec<_>>();
-    let max_price = slice.iter().copied().fold(f64::MIN, f64::max);
-    let min_price = slice.iter().copied().fold(f64::MAX, f64::min);
-    Some(min_price - max_price)
+/// Ichimoku kinko hyo. Les lignes avancées (span A et B) et la ligne retardée sont décalées de
+/// `displacement − 1` points comme sur TradingView ; la partie du nuage projetée au-delà du
+/// dernier point n'est pas renvoyée.
+pub struct IchimokuSeries {
+    /// Tenkan-sen : milieu de l'étendue sur `conversion` points
+    pub conversion: Vec<Option<f64>>,
+    /// Kijun-sen : milieu de l'étendue sur `base` points
+    pub base: Vec<Option<f64>>,
+    /// Senkou span A : (tenkan + kijun)/2, avancé
+    pub span_a: Vec<Option<f64>>,
+    /// Senkou span B : milieu de l'étendue sur `span_b` points, avancé
+    pub span_b: Vec<Option<f64>>,
+    /// Chikou span : clôture, retardée
+    pub lagging: Vec<Option<f64>>,
 }
 
-pub fn trend(points: &[StockPoint]) -> Option<String> {
-    if points.is_empty() { return None; }
-    let first = points.first()?.close;
-    let last = points.last()?.close;
-    Some(if last >= first { "Haussier".to_string() } else { "Baissier".to_string() })
+pub fn ichimoku_series(points: &[StockPoint], conversion: usize, base: usize, span_b: usize, displacement: usize) -> IchimokuSeries {
+    let midpoint = |w: &[StockPoint]| (highest_high(w) + lowest_low(w)) / 2.0;
+    let conversion = rolling(points, conversion, midpoint);
+    let base = rolling(points, base, midpoint);
+    let lead_a: Vec<Option<f64>> = conversion.iter().zip(&base).map(|(c, b)| Some(((*c)? + (*b)?) / 2.0)).collect();
+    let lead_b = rolling(points, span_b, midpoint);
+
+    let shift = displacement.saturating_sub(1);
+    let forward = |lead: &[Option<f64>]| -> Vec<Option<f64>> {
+        (0..points.len()).map(|i| i.checked_sub(shift).and_then(|j| lead[j])).collect()
+    };
+    IchimokuSeries {
+        span_a: forward(&lead_a),
+        span_b: forward(&lead_b),
+        lagging: (0..points.len()).map(|i| points.get(i + shift).map(|p| p.close)).collect(),
+        conversion,
+        base,
+    }
 }
 
-pub fn cumulative_gain(points: &[StockPoint], period: usize) -> Option<f64> {
-    if points.len() < period { return None; }
-    let slice = points.iter().rev().take(period);
-    Some(slice.map(|p| p.close - p.open).sum())
+/// Aroon : 100·(period − points écoulés depuis le plus haut [bas])/period, sur `period + 1` points
+pub struct AroonSeries {
+    pub up: Vec<Option<f64>>,
+    pub down: Vec<Option<f64>>,
 }
 
-pub fn volume_avg(points: &[StockPoint], period: usize) -> Option<f64> {
-    if points.len() < period { return None; }
-    let slice = points.iter().rev().take(period);
-    Some(slice.map(|p| p.volume as f64).sum::<f64>() / period as f64)
+pub fn aroon_series(points: &[StockPoint], period: usize) -> AroonSeries {
+    if period == 0 {
+        return AroonSeries { up: vec![None; points.len()], down: vec![None; points.len()] };
+    }
+    let score = |since: usize| 100.0 * (period - since) as f64 / period as f64;
+    let scores = rolling(points, period + 1, |w| {
+        let last = w.len() - 1;
+        // En cas d'égalité, l'extrême le plus récent est retenu
+        let highest = (0..w.len()).fold(0, |best, i| if w[i].high >= w[best].high { i } else { best });
+        let lowest = (0..w.len()).fold(0, |best, i| if w[i].low <= w[best].low { i } else { best });
+        (score(last - highest), score(last - lowest))
+    });
+    AroonSeries {
+        up: scores.iter().map(|s| s.map(|s| s.0)).collect(),
+        down: scores.iter().map(|s| s.map(|s| s.1)).collect(),
+    }
 }
 
-pub fn volatility(points: &[StockPoint], period: usize) -> Option<f64> {
-    if points.len() < period { return None; }
-    let slice = points.iter().rev().take(period).collect::<Vec<_>>();
-    let mean = slice.iter().map(|p| p.close).sum::<f64>() / period as f64;
-    let variance = slice.iter().map(|p| (p.close - mean).powi(2)).sum::<f64>() / period as f64;
-    Some(variance.sqrt())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration};
+
+    /// Bougies quotidiennes ; sans haut ni bas fournis, ils valent la clôture
+    fn candles(highs: &[f64], lows: &[f64], closes: &[f64]) -> Vec<StockPoint> {
+        let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, &close)| StockPoint {
+                timestamp: start + Duration::days(i as i64),
+                open: close,
+                high: highs.get(i).copied().unwrap_or(close),
+                low: lows.get(i).copied().unwrap_or(close),
+                close,
+                volume: 0.0,
+            })
+            .collect()
+    }
+
+    fn from_closes(closes: &[f64]) -> Vec<StockPoint> {
+        candles(&[], &[], closes)
+    }
+
+    /// Compare une série aux valeurs attendues à partir de `first`, `None` avant
+    fn assert_series(series: &[Option<f64>], first: usize, expected: &[f64], tolerance: f64) {
+        assert_eq!(series.len(), first + expected.len());
+        assert!(series[..first].iter().all(Option::is_none), "valeurs avant l'indice {}", first);
+        for (i, (value, expected)) in series[first..].iter().zip(expected).enumerate() {
+            let value = value.unwrap_or_else(|| panic!("valeur absente à l'indice {}", first + i));
+            assert!(
+                (value - expected).abs() <= tolerance,
+                "indice {} : {} au lieu de {}",
+                first + i,
+                value,
+                expected
+            );
+        }
+    }
+
+    // Exemple RSI(14) de StockCharts
+    const RSI_CLOSES: [f64; 33] = [
+        44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42, 45.84, 46.08, 45.89, 46.03, 45.61, 46.28, 46.28, 46.00,
+        46.03, 46.41, 46.22, 45.64, 46.21, 46.25, 45.71, 46.45, 45.78, 45.35, 44.03, 44.18, 44.22, 44.57, 43.42, 42.66,
+        43.13,
+    ];
+
+    #[test]
+    fn rsi_matches_stockcharts_with_wilder_seeding() {
+        let rsi = rsi_series(&from_closes(&RSI_CLOSES), 14);
+        // StockCharts arrondit les moyennes de gains et de pertes à deux décimales, d'où un écart < 0,1
+        let published = [
+            70.53, 66.32, 66.55, 69.41, 66.36, 57.97, 62.93, 63.26, 56.06, 62.38, 54.71, 50.42, 39.99, 41.46, 41.87,
+            45.46, 37.30, 33.08, 37.77,
+        ];
+        assert_series(&rsi, 14, &published, 0.1);
+        // Sans arrondi : gain moyen 3,34/14 et perte moyenne 1,40/14 sur les 14 premières variations
+        let exact = 100.0 - 100.0 / (1.0 + 3.34 / 1.40);
+        assert!((rsi[14].unwrap() - exact).abs() < 1e-9);
+    }
+
+    #[test]
+    fn atr_uses_wilder_smoothing_seeded_on_the_first_true_ranges() {
+        // Exemple ATR(14) de StockCharts ; valeurs de Wilder recalculées à deux décimales
+        let highs = [
+            48.70, 48.72, 48.90, 48.87, 48.82, 49.05, 49.20, 49.35, 49.92, 50.19, 50.12, 49.66, 49.88, 50.19, 50.36,
+            50.57, 50.65, 50.43, 49.63, 50.33, 50.29, 50.17, 49.32, 48.50, 48.32, 46.80, 47.80, 48.39, 48.66, 48.79,
+        ];
+        let lows = [
+            47.79, 48.14, 48.39, 48.37, 48.24, 48.64, 48.94, 48.86, 49.50, 49.87, 49.20, 48.90, 49.43, 49.73, 49.26,
+            50.09, 50.30, 49.21, 48.98, 49.61, 49.20, 49.43, 48.08, 47.64, 41.55, 44.28, 47.31, 47.20, 47.90, 47.73,
+        ];
+        let closes = [
+            48.16, 48.61, 48.75, 48.63, 48.74, 49.03, 49.07, 49.32, 49.91, 50.13, 49.53, 49.50, 49.75, 50.03, 50.31,
+            50.52, 50.41, 49.34, 49.37, 50.23, 49.24, 49.93, 48.43, 48.18, 46.57, 45.41, 47.77, 47.72, 48.62, 47.85,
+        ];
+        let atr = atr_series(&candles(&highs, &lows, &closes), 14);
+        let expected = [
+            0.55, 0.59, 0.59, 0.57, 0.61, 0.62, 0.64, 0.67, 0.69, 0.77, 0.78, 1.21, 1.30, 1.38, 1.37, 1.34, 1.32,
+        ];
+        assert_series(&atr, 13, &expected, 0.006);
+        // Amorçage : moyenne des 14 premiers true ranges, le premier valant haut − bas
+        let seed: f64 = true_ranges(&candles(&highs, &lows, &closes))[..14].iter().map(|tr| tr.unwrap()).sum::<f64>() / 14.0;
+        assert!((atr[13].unwrap() - seed).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ema_is_seeded_on_the_sma() {
+        // Exemple EMA(10) de StockCharts
+        let closes = [
+            22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24, 22.29, 22.15, 22.39, 22.38, 22.61, 23.36,
+            24.05, 23.75, 23.83, 23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68, 23.10, 22.40, 22.17,
+        ];
+        let points = from_closes(&closes);
+        let ema = ema_series(&points, 10);
+        let published = [
+            22.22, 22.21, 22.24, 22.27, 22.33, 22.52, 22.80, 22.97, 23.13, 23.28, 23.34, 23.43, 23.51, 23.53, 23.47,
+            23.40, 23.39, 23.26, 23.23, 23.08, 22.92,
+        ];
+        assert_series(&ema, 9, &published, 0.006);
+        assert_eq!(ema[9], sma_series(&points, 10)[9]);
+    }
+
+    #[test]
+    fn macd_12_26_9_on_a_linear_trend() {
+        // Sur une droite de pente 1, une EMA amorcée par la SMA retarde exactement de (n − 1)/2 :
+        // ligne = 12,5 − 5,5 = 7 dès le 26e point, signal = 7 dès le 34e, histogramme nul
+        let closes: Vec<f64> = (0..60).map(|i| 100.0 + i as f64).collect();
+        let macd = macd_lines(&from_closes(&closes), 12, 26, 9);
+        assert_series(&macd.line, 25, &[7.0; 35], 1e-9);
+        assert_series(&macd.signal, 33, &[7.0; 27], 1e-9);
+        assert_series(&macd.histogram, 33, &[0.0; 27], 1e-9);
+    }
+
+    #[test]
+    fn macd_signal_is_seeded_on_the_mean_of_the_first_lines() {
+        let points = from_closes(&RSI_CLOSES);
+        let macd = macd_lines(&points, 3, 6, 4);
+        let fast = ema_series(&points, 3);
+        let slow = ema_series(&points, 6);
+        for i in 5..points.len() {
+            assert!((macd.line[i].unwrap() - (fast[i].unwrap() - slow[i].unwrap())).abs() < 1e-12);
+        }
+        let seed = (5..9).map(|i| macd.line[i].unwrap()).sum::<f64>() / 4.0;
+        assert!(macd.signal[7].is_none());
+        assert!((macd.signal[8].unwrap() - seed).abs() < 1e-12);
+        assert!((macd.histogram[8].unwrap() - (macd.line[8].unwrap() - seed)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn max_drawdown_measures_the_deepest_fall_from_a_prior_peak() {
+        let points = from_closes(&[10.0, 12.0, 9.0, 11.0, 8.0, 13.0, 12.0]);
+        assert_series(&max_drawdown_series(&points, 7), 6, &[-4.0], 0.0);
+        assert_series(&max_drawdown_series(&points, 3), 2, &[-3.0, -3.0, -3.0, -3.0, -1.0], 0.0);
+        // Une série croissante n'a pas de baisse
+        let rising = from_closes(&[1.0, 2.0, 3.0, 4.0]);
+        assert_series(&max_drawdown_series(&rising, 4), 3, &[0.0], 0.0);
+    }
+
+    // ---- Oscillateurs, volumes et canaux ----
+    // Valeurs attendues calculées à la main selon les définitions de référence
+    // (Lane, Williams, Lambert, Granville, Chaikin, Wilder, Hosoda, Chande)
+
+    /// Huit bougies avec volumes, dont un volume nul
+    fn ohlcv() -> Vec<StockPoint> {
+        let highs = [10.0, 11.0, 12.0, 11.5, 13.0, 12.5, 12.0, 14.0];
+        let lows = [8.0, 9.0, 10.0, 9.0, 10.0, 11.0, 10.5, 11.0];
+        let closes = [9.5, 10.0, 11.5, 9.5, 12.5, 11.5, 11.0, 13.5];
+        let volumes = [100.0, 200.0, 150.0, 100.0, 300.0, 250.0, 0.0, 400.0];
+        let mut points = candles(&highs, &lows, &closes);
+        for (point, volume) in points.iter_mut().zip(volumes) {
+            point.volume = volume;
+        }
+        points
+    }
+
+    #[test]
+    fn stochastic_and_williams_r_use_the_window_range() {
+        let points = ohlcv();
+        // %K au point 2 : 100·(11,5 − 8)/(12 − 8)
+        let stochastic = stochastic_series(&points, 3, 1, 2);
+        assert_series(&stochastic.k, 2, &[87.5, 16.6667, 87.5, 62.5, 33.3333, 85.7143], 1e-4);
+        assert_series(&stochastic.d, 3, &[52.0833, 52.0833, 75.0, 47.9167, 59.5238], 1e-4);
+        // Lissage de %K en SMA(2)
+        let smoothed = stochastic_series(&points, 3, 2, 1);
+        assert_series(&smoothed.k, 3, &[52.0833, 52.0833, 75.0, 47.9167, 59.5238], 1e-4);
+
+        let williams = williams_r_series(&points, 3);
+        assert_series(&williams, 2, &[-12.5, -83.3333, -12.5, -37.5, -66.6667, -14.2857], 1e-4);
+    }
+
+    #[test]
+    fn cci_scales_the_mean_absolute_deviation() {
+        // Point 2 : prix typiques 9, 10, 11 ; écart moyen 2/3 ; (11 − 10)/(0,015·2/3) = 100
+        let cci = cci_series(&ohlcv(), 3);
+        assert_series(&cci, 2, &[100.0, -50.0, 83.3333, 42.8571, -100.0, 100.0], 1e-4);
+    }
+
+    #[test]
+    fn volume_indicators_match_their_definitions() {
+        let points = ohlcv();
+        assert_series(&obv_series(&points), 0, &[0.0, 200.0, 350.0, 250.0, 550.0, 300.0, 300.0, 700.0], 0.0);
+        // Point 1 : (9,1667·100 + 10·200)/300
+        assert_series(&vwap_series(&points, 2), 1, &[9.7222, 10.5, 10.7, 11.375, 11.7576, 11.6667, 12.8333], 1e-4);
+        assert_series(
+            &chaikin_money_flow_series(&points, 3),
+            2,
+            &[0.2778, 0.0333, 0.3909, 0.0872, 0.2121, 0.2821],
+            1e-4,
+        );
+        assert_series(&mfi_series(&points, 3), 3, &[78.6096, 83.9357, 47.5446, 54.8969, 63.7681], 1e-4);
+    }
+
+    #[test]
+    fn dmi_uses_wilder_smoothing_from_the_second_point() {
+        let dmi = dmi_series(&ohlcv(), 3, 2);
+        assert_series(&dmi.plus_di, 3, &[30.7692, 36.1702, 28.0992, 21.0526, 40.636], 1e-4);
+        assert_series(&dmi.minus_di, 3, &[15.3846, 8.5106, 6.6116, 13.3127, 7.5972], 1e-4);
+        assert_series(&dmi.adx, 4, &[47.619, 54.7619, 38.6422, 53.5702], 1e-4);
+    }
+
+    #[test]
+    fn channels_surround_their_middle_line() {
+        let points = ohlcv();
+        // Keltner : EMA(2) ± 2·ATR(2), tous deux amorcés sur les deux premiers points
+        let keltner = keltner_series(&points, 2, 2.0, 2);
+        assert_series(&keltner.middle, 1, &[9.75, 10.9167, 9.9722, 11.6574, 11.5525, 11.1842, 12.7281], 1e-4);
+        assert_series(&keltner.upper, 1, &[13.75, 14.9167, 14.4722, 17.4074, 15.9275, 14.8717, 17.5718], 1e-4);
+        assert_series(&keltner.lower, 1, &[5.75, 6.9167, 5.4722, 5.9074, 7.1775, 7.4967, 7.8843], 1e-4);
+
+        let donchian = donchian_series(&points, 3);
+        assert_series(&donchian.upper, 2, &[12.0, 12.0, 13.0, 13.0, 13.0, 14.0], 0.0);
+        assert_series(&donchian.lower, 2, &[8.0, 9.0, 9.0, 9.0, 10.0, 10.5], 0.0);
+        assert_series(&donchian.middle, 2, &[10.0, 10.5, 11.0, 11.0, 11.5, 12.25], 0.0);
+    }
+
+    #[test]
+    fn aroon_counts_points_since_the_latest_extreme() {
+        // Point 3 : plus haut deux points plus tôt (66,7), plus bas au début de la fenêtre (0)
+        let aroon = aroon_series(&ohlcv(), 3);
+        assert_series(&aroon.up, 3, &[66.6667, 100.0, 66.6667, 33.3333, 100.0], 1e-4);
+        assert_series(&aroon.down, 3, &[0.0, 66.6667, 33.3333, 0.0, 0.0], 1e-4);
+    }
+
+    #[test]
+    fn ichimoku_shifts_the_leading_and_lagging_lines() {
+        let ichimoku = ichimoku_series(&ohlcv(), 2, 3, 4, 2);
+        assert_series(&ichimoku.conversion, 1, &[9.5, 10.5, 10.5, 11.0, 11.5, 11.5, 12.25], 0.0);
+        assert_series(&ichimoku.base, 2, &[10.0, 10.5, 11.0, 11.0, 11.5, 12.25], 0.0);
+        assert_series(&ichimoku.span_a, 3, &[10.25, 10.5, 11.0, 11.25, 11.5], 0.0);
+        assert_series(&ichimoku.span_b, 4, &[10.0, 11.0, 11.0, 11.0], 0.0);
+        // La ligne retardée au point i est la clôture du point i + 1
+        assert_eq!(ichimoku.lagging[..7], [10.0, 11.5, 9.5, 12.5, 11.5, 11.0, 13.5].map(Some));
+        assert_eq!(ichimoku.lagging[7], None);
+    }
+
+    #[test]
+    fn parabolic_sar_folds_the_reversal_bar_into_the_new_stop() {
+        // Hausse jusqu'au point 3, puis un point 4 qui dépasse l'extrême (14 > 13) avant de crever
+        // le SAR : le nouveau SAR baissier part de 14, et non de l'extrême précédent
+        let highs = [10.0, 11.0, 12.0, 13.0, 14.0, 12.0, 11.0];
+        let lows = [9.0, 10.0, 11.0, 12.0, 8.0, 9.0, 8.5];
+        let closes = [9.5, 10.5, 11.5, 12.5, 8.5, 9.5, 9.0];
+        let sar = parabolic_sar_series(&candles(&highs, &lows, &closes), 0.02, 0.02, 0.2);
+        // Point 2 : 9 + 0,02·(11 − 9), ramené au plus bas des deux points précédents (9) ;
+        // point 3 : 9 + 0,04·(12 − 9) ; points 5 et 6 : 14 + 0,02·(8 − 14), relevé au plus haut
+        // des deux points précédents, qui inclut toujours le point de retournement (14)
+        assert_series(&sar, 1, &[9.0, 9.0, 9.12, 14.0, 14.0, 14.0], 1e-9);
+    }
 }
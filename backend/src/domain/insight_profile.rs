@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::indicator_series::IndicatorSpec;
+
+/// Nombre maximal d'indicateurs par profil
+pub const MAX_PROFILE_INDICATORS: usize = 50;
+
+/// Alerte levée quand la dernière valeur d'une courbe franchit un seuil
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InsightThreshold {
+    /// Nom de l'alerte ("overbought")
+    pub name: String,
+    /// Courbe surveillée pour un indicateur à plusieurs courbes ("k", "adx"…)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<String>,
+    /// Alerte si la valeur dépasse strictement ce seuil
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub above: Option<f64>,
+    /// Alerte si la valeur passe strictement sous ce seuil
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub below: Option<f64>,
+}
+
+impl InsightThreshold {
+    fn new(name: &str, line: Option<&str>, above: Option<f64>, below: Option<f64>) -> Self {
+        Self { name: name.to_string(), line: line.map(str::to_string), above, below }
+    }
+
+    pub fn triggered(&self, value: f64) -> bool {
+        self.above.is_some_and(|t| value > t) || self.below.is_some_and(|t| value < t)
+    }
+}
+
+/// Indicateur d'un profil, au format des specs de `/indicators` ("rsi:14", "keltner:20:2:10")
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InsightIndicator {
+    pub spec: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub thresholds: Vec<InsightThreshold>,
+}
+
+impl InsightIndicator {
+    fn new(spec: &str, thresholds: Vec<InsightThreshold>) -> Self {
+        Self { spec: spec.to_string(), thresholds }
+    }
+}
+
+/// Liste d'indicateurs et de seuils calculés dans les insights d'un symbole
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InsightProfile {
+    pub name: String,
+    /// Utilisateur propriétaire ; absent pour un profil partagé
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub indicators: Vec<InsightIndicator>,
+}
+
+pub const DEFAULT_PROFILE: &str = "default";
+
+impl InsightProfile {
+    fn builtin(name: &str, description: &str, indicators: Vec<InsightIndicator>) -> Self {
+        Self {
+            name: name.to_string(),
+            owner: None,
+            description: Some(description.to_string()),
+            indicators,
+        }
+    }
+
+    /// Profils fournis d'office ; "default" reprend les insights historiques
+    pub fn builtins() -> Vec<Self> {
+        let plain = |spec: &str| InsightIndicator::new(spec, Vec::new());
+        let rsi = || {
+            InsightIndicator::new("rsi:14", vec![
+                InsightThreshold::new("overbought", None, Some(70.0), None),
+                InsightThreshold::new("oversold", None, None, Some(30.0)),
+            ])
+        };
+        vec![
+            Self::builtin("default", "Indicateurs généraux : moyennes, Bollinger, RSI, MACD, ATR, volumes", vec![
+                plain("day_change"),
+                plain("day_change_percent"),
+                plain("sma:7"),
+                plain("sma:30"),
+                plain("ema:7"),
+                plain("ema:30"),
+                plain("bollinger:20"),
+                rsi(),
+                plain("macd"),
+                plain("atr:14"),
+                plain("max_drawdown:30"),
+                plain("cumulative_gain:30"),
+                plain("volume_avg:30"),
+                plain("volatility:30"),
+            ]),
+            Self::builtin("momentum", "Oscillateurs de momentum et zones de surachat / survente", vec![
+                rsi(),
+                InsightIndicator::new("stochastic", vec![
+                    InsightThreshold::new("stochastic_overbought", Some("k"), Some(80.0), None),
+                    InsightThreshold::new("stochastic_oversold", Some("k"), None, Some(20.0)),
+                ]),
+                plain("williams_r"),
+                InsightIndicator::new("mfi", vec![
+                    InsightThreshold::new("mfi_overbought", None, Some(80.0), None),
+                    InsightThreshold::new("mfi_oversold", None, None, Some(20.0)),
+                ]),
+                plain("cci"),
+                plain("macd"),
+            ]),
+            Self::builtin("trend", "Suivi de tendance : moyennes longues, DMI, Aroon, SAR, Ichimoku", vec![
+                plain("sma:50"),
+                plain("sma:200"),
+                plain("ema:20"),
+                InsightIndicator::new("dmi", vec![InsightThreshold::new("strong_trend", Some("adx"), Some(25.0), None)]),
+                plain("aroon"),
+                plain("psar"),
+                plain("ichimoku"),
+            ]),
+            Self::builtin("volatility", "Volatilité et canaux de prix", vec![
+                plain("bollinger:20"),
+                plain("keltner"),
+                plain("donchian"),
+                plain("atr:14"),
+                plain("volatility:30"),
+                plain("max_drawdown:30"),
+            ]),
+        ]
+    }
+
+    pub fn builtin_named(name: &str) -> Option<Self> {
+        Self::builtins().into_iter().find(|p| p.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Vérifie le nom, le nombre d'indicateurs, leurs specs et leurs seuils
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Le nom du profil est vide".to_string());
+        }
+        if self.indicators.is_empty() || self.indicators.len() > MAX_PROFILE_INDICATORS {
+            return Err(format!(
+                "{} indicateurs dans le profil (attendu entre 1 et {})",
+                self.indicators.len(),
+                MAX_PROFILE_INDICATORS
+            ));
+        }
+        for indicator in &self.indicators {
+            // Les courbes produites ne dépendent que de l'indicateur : une série vide suffit à les lister
+            let lines = IndicatorSpec::parse(&indicator.spec)?.series(&[]).lines;
+            for threshold in &indicator.thresholds {
+                if threshold.name.trim().is_empty() {
+                    return Err(format!("Seuil sans nom pour '{}'", indicator.spec));
+                }
+                if threshold.above.is_none() && threshold.below.is_none() {
+                    return Err(format!("Le seuil '{}' n'a ni borne haute ni borne basse", threshold.name));
+                }
+                match &threshold.line {
+                    Some(line) if !lines.contains_key(line.as_str()) => {
+                        let available: Vec<&str> = lines.keys().copied().collect();
+                        return Err(format!(
+                            "Courbe '{}' inconnue pour '{}' (disponibles : {})",
+                            line,
+                            indicator.spec,
+                            available.join(", ")
+                        ));
+                    }
+                    None if lines.len() > 1 => {
+                        return Err(format!("Le seuil '{}' doit préciser la courbe de '{}'", threshold.name, indicator.spec));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+}
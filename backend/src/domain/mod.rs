@@ -4,5 +4,18 @@ pub mod generic_stock_data_dto;
 pub mod time_series;
 pub mod stock_insights;
 pub mod stock_insights_builder;
+pub mod insight_profile;
 pub mod indicators;
+pub mod indicator_series;
+pub mod indicator_stream;
 pub mod prediction_point;
+pub mod statistics;
+pub mod forecast;
+pub mod forecast_metrics;
+pub mod forecast_record;
+pub mod garch;
+pub mod hmm;
+pub mod decomposition;
+pub mod regime;
+pub mod trading_calendar;
+pub mod tuning;
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use crate::domain::time_series::StockPoint;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PredictionPoint {
@@ -14,3 +15,10 @@ impl PredictionPoint {
         Self { timestamp, close, upper, lower }
     }
 }
+
+/// Un point historique sert d'entrée aux prédicteurs : bande nulle autour du cours de clôture
+impl From<&StockPoint> for PredictionPoint {
+    fn from(point: &StockPoint) -> Self {
+        Self::new(point.timestamp, point.close, point.close, point.close)
+    }
+}
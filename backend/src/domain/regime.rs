@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::domain::hmm::GaussianHmm;
+use crate::domain::time_series::StockPoint;
+
+/// Régime de marché associé à un état caché
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Regime {
+    Bull,
+    Bear,
+    HighVolatility,
+}
+
+/// Caractéristiques d'un état : rendement moyen et volatilité journaliers
+#[derive(Debug, Clone, Serialize)]
+pub struct RegimeState {
+    pub regime: Regime,
+    pub mean_return: f64,
+    pub volatility: f64,
+    /// Durée moyenne d'un séjour dans l'état, 1 / (1 - p_ii)
+    pub expected_duration: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RegimePoint {
+    pub timestamp: DateTime<Utc>,
+    pub regime: Regime,
+    /// Probabilité a posteriori du régime retenu
+    pub probability: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RegimeAnalysis {
+    pub states: Vec<RegimeState>,
+    /// Matrice de transition, lignes et colonnes dans l'ordre de `states`
+    pub transition: Vec<Vec<f64>>,
+    pub log_likelihood: f64,
+    pub current: Regime,
+    pub sequence: Vec<RegimePoint>,
+}
+
+/// Détection de régimes par HMM gaussien à 2 ou 3 états sur les rendements logarithmiques
+/// de points triés (journaliers ou plus grossiers). Avec 3 états, le plus volatil est le régime
+/// de forte volatilité ; les autres sont haussier ou baissier selon leur rendement moyen.
+pub fn detect_regimes(points: &[StockPoint], states: usize) -> Option<RegimeAnalysis> {
+    // Rendements et horodatages alignés (les prix non positifs sont écartés)
+    let (timestamps, returns): (Vec<DateTime<Utc>>, Vec<f64>) = points
+        .windows(2)
+        .filter(|w| w[0].close > 0.0 && w[1].close > 0.0)
+        .map(|w| (w[1].timestamp, (w[1].close / w[0].close).ln()))
+        .unzip();
+
+    let model = GaussianHmm::fit(&returns, states)?;
+    let labels = label_states(&model);
+    let posteriors = model.posteriors(&returns)?;
+    let path = model.viterbi(&returns);
+
+    let sequence: Vec<RegimePoint> = path
+        .iter()
+        .zip(&timestamps)
+        .zip(&posteriors)
+        .map(|((state, timestamp), posterior)| RegimePoint {
+            timestamp: *timestamp,
+            regime: labels[*state],
+            probability: posterior[*state],
+        })
+        .collect();
+
+    let regime_states = (0..model.states())
+        .map(|s| RegimeState {
+            regime: labels[s],
+            mean_return: model.means[s],
+            volatility: model.variances[s].sqrt(),
+            expected_duration: 1.0 / (1.0 - model.transition[s][s]).max(f64::EPSILON),
+        })
+        .collect();
+
+    Some(RegimeAnalysis {
+        states: regime_states,
+        transition: model.transition.clone(),
+        log_likelihood: model.log_likelihood,
+        current: sequence.last()?.regime,
+        sequence,
+    })
+}
+
+fn label_states(model: &GaussianHmm) -> Vec<Regime> {
+    let k = model.states();
+    let volatile = if k >= 3 {
+        (0..k).max_by(|a, b| model.variances[*a].total_cmp(&model.variances[*b]))
+    } else {
+        None
+    };
+    let bear = (0..k)
+        .filter(|s| Some(*s) != volatile)
+        .min_by(|a, b| model.means[*a].total_cmp(&model.means[*b]));
+
+    (0..k)
+        .map(|s| {
+            if Some(s) == volatile {
+                Regime::HighVolatility
+            } else if Some(s) == bear {
+                Regime::Bear
+            } else {
+                Regime::Bull
+            }
+        })
+        .collect()
+}
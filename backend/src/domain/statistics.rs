@@ -0,0 +1,502 @@
+/// Moyenne arithmétique d'une série (0 si vide)
+pub fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Autocovariances empiriques γ(0)..γ(max_lag), estimateur biaisé (divisé par n)
+pub fn autocovariances(values: &[f64], max_lag: usize) -> Vec<f64> {
+    let n = values.len();
+    let m = mean(values);
+    (0..=max_lag)
+        .map(|lag| {
+            if lag >= n {
+                return 0.0;
+            }
+            values[lag..]
+                .iter()
+                .zip(values.iter())
+                .map(|(a, b)| (a - m) * (b - m))
+                .sum::<f64>()
+                / n as f64
+        })
+        .collect()
+}
+
+/// Résolution récursive des équations de Yule-Walker (Levinson-Durbin).
+/// Renvoie, pour chaque ordre p de 0 à max_order, les coefficients φ1..φp
+/// et la variance du bruit associée.
+pub fn levinson_durbin(acov: &[f64], max_order: usize) -> Vec<(Vec<f64>, f64)> {
+    let mut results = vec![(Vec::new(), acov.first().copied().unwrap_or(0.0))];
+    let mut phi: Vec<f64> = Vec::new();
+    let mut sigma2 = acov.first().copied().unwrap_or(0.0);
+
+    for k in 1..=max_order.min(acov.len().saturating_sub(1)) {
+        if sigma2 <= 0.0 {
+            break;
+        }
+        let acc: f64 = (1..k).map(|j| phi[j - 1] * acov[k - j]).sum();
+        let reflection = (acov[k] - acc) / sigma2;
+
+        let mut next = vec![0.0; k];
+        for j in 1..k {
+            next[j - 1] = phi[j - 1] - reflection * phi[k - j - 1];
+        }
+        next[k - 1] = reflection;
+
+        sigma2 *= 1.0 - reflection * reflection;
+        phi = next;
+        results.push((phi.clone(), sigma2));
+    }
+
+    results
+}
+
+/// Critère d'information d'Akaike pour un modèle gaussien à `params` paramètres
+pub fn aic(noise_variance: f64, n: usize, params: usize) -> f64 {
+    n as f64 * noise_variance.max(f64::MIN_POSITIVE).ln() + 2.0 * params as f64
+}
+
+/// Différence première d'une série : x_t - x_{t-1}
+pub fn difference(values: &[f64]) -> Vec<f64> {
+    values.windows(2).map(|w| w[1] - w[0]).collect()
+}
+
+/// Résultat d'une régression linéaire par moindres carrés ordinaires
+#[derive(Debug, Clone)]
+pub struct OlsFit {
+    pub coefficients: Vec<f64>,
+    pub std_errors: Vec<f64>,
+}
+
+/// Moindres carrés ordinaires y = Xβ + ε (X fourni ligne par ligne).
+/// Renvoie None si le système est mal posé (pas assez d'observations ou X'X singulière).
+pub fn ols(design: &[Vec<f64>], y: &[f64]) -> Option<OlsFit> {
+    let n = y.len();
+    let k = design.first()?.len();
+    if n != design.len() || n <= k {
+        return None;
+    }
+
+    let mut xtx = vec![vec![0.0; k]; k];
+    let mut xty = vec![0.0; k];
+    for (row, target) in design.iter().zip(y) {
+        for i in 0..k {
+            xty[i] += row[i] * target;
+            for j in 0..k {
+                xtx[i][j] += row[i] * row[j];
+            }
+        }
+    }
+
+    let inverse = invert(&xtx)?;
+    let coefficients: Vec<f64> = (0..k)
+        .map(|i| (0..k).map(|j| inverse[i][j] * xty[j]).sum())
+        .collect();
+
+    let residuals: Vec<f64> = design
+        .iter()
+        .zip(y)
+        .map(|(row, target)| target - row.iter().zip(&coefficients).map(|(x, b)| x * b).sum::<f64>())
+        .collect();
+
+    let sigma2 = residuals.iter().map(|r| r * r).sum::<f64>() / (n - k) as f64;
+    let std_errors = (0..k).map(|i| (sigma2 * inverse[i][i]).max(0.0).sqrt()).collect();
+
+    Some(OlsFit { coefficients, std_errors })
+}
+
+/// Inversion de matrice carrée par élimination de Gauss-Jordan avec pivot partiel
+pub fn invert(matrix: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let n = matrix.len();
+    let mut a: Vec<Vec<f64>> = matrix.to_vec();
+    let mut inv: Vec<Vec<f64>> = (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&r1, &r2| a[r1][col].abs().total_cmp(&a[r2][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        inv.swap(col, pivot);
+
+        let p = a[col][col];
+        for j in 0..n {
+            a[col][j] /= p;
+            inv[col][j] /= p;
+        }
+
+        for row in 0..n {
+            if row == col {
+                continue;
+            }
+            let factor = a[row][col];
+            if factor == 0.0 {
+                continue;
+            }
+            for j in 0..n {
+                a[row][j] -= factor * a[col][j];
+                inv[row][j] -= factor * inv[col][j];
+            }
+        }
+    }
+
+    Some(inv)
+}
+
+/// Statistique du test de Dickey-Fuller augmenté (modèle avec constante) :
+/// Δy_t = α + β·y_{t-1} + Σ γ_i·Δy_{t-i} + ε_t, renvoie le t de Student de β.
+pub fn adf_statistic(values: &[f64], lags: usize) -> Option<f64> {
+    let diffs = difference(values);
+    if diffs.len() <= lags + 3 {
+        return None;
+    }
+
+    let mut design = Vec::new();
+    let mut target = Vec::new();
+    for t in lags..diffs.len() {
+        let mut row = vec![1.0, values[t]];
+        row.extend((1..=lags).map(|i| diffs[t - i]));
+        design.push(row);
+        target.push(diffs[t]);
+    }
+
+    let fit = ols(&design, &target)?;
+    if fit.std_errors[1] == 0.0 {
+        return None;
+    }
+    Some(fit.coefficients[1] / fit.std_errors[1])
+}
+
+/// Test ADF au seuil de 5 % (valeur critique de MacKinnon, modèle avec constante).
+/// Une série trop courte ou constante est considérée stationnaire.
+pub fn is_stationary(values: &[f64]) -> bool {
+    let n = values.len();
+    let lags = ((n.saturating_sub(1)) as f64).cbrt().floor() as usize;
+    match adf_statistic(values, lags) {
+        Some(stat) => {
+            let nf = n as f64;
+            let critical = -2.8621 - 2.738 / nf - 8.36 / (nf * nf);
+            stat < critical
+        }
+        None => true,
+    }
+}
+
+/// Quantile de la loi normale centrée réduite (approximation rationnelle d'Acklam, erreur < 1.2e-9)
+pub fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1, 2.209460984245205e2, -2.759285104469687e2,
+        1.38357751867269e2, -3.066479806614716e1, 2.506628277459239,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1, 1.615858368580409e2, -1.556989798598866e2,
+        6.680131188771972e1, -1.328068155288572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3, -3.223964580411365e-1, -2.400758277161838,
+        -2.549732539343734, 4.374664141464968, 2.938163982698783,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3, 3.224671290700398e-1, 2.445134137142996, 3.754408661907416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+
+    if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -normal_quantile(1.0 - p)
+    }
+}
+
+/// Poids ψ de la représentation MA(∞) d'un ARMA : ψ_0 = 1, ψ_j = θ_j + Σ φ_i ψ_{j-i}
+pub fn psi_weights(ar: &[f64], ma: &[f64], count: usize) -> Vec<f64> {
+    let mut psi = Vec::with_capacity(count);
+    for j in 0..count {
+        if j == 0 {
+            psi.push(1.0);
+            continue;
+        }
+        let ma_part = ma.get(j - 1).copied().unwrap_or(0.0);
+        let ar_part: f64 = ar
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i < j)
+            .map(|(i, phi)| phi * psi[j - 1 - i])
+            .sum();
+        psi.push(ma_part + ar_part);
+    }
+    psi
+}
+
+/// Écart-type de l'erreur de prévision à chaque horizon h = 1..count : σ·sqrt(Σ_{j<h} ψ_j²)
+pub fn forecast_std_devs(psi: &[f64], noise_variance: f64) -> Vec<f64> {
+    let mut acc = 0.0;
+    psi.iter()
+        .map(|w| {
+            acc += w * w;
+            (noise_variance * acc).sqrt()
+        })
+        .collect()
+}
+
+/// Racine de l'erreur quadratique moyenne
+pub fn rms(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    (values.iter().map(|v| v * v).sum::<f64>() / values.len() as f64).sqrt()
+}
+
+/// Minimisation sans gradient par la méthode du simplexe de Nelder-Mead.
+/// `step` fixe la taille du simplexe initial autour de `start`.
+pub fn nelder_mead<F: Fn(&[f64]) -> f64>(f: F, start: &[f64], step: f64, max_iter: usize) -> Vec<f64> {
+    let dim = start.len();
+    if dim == 0 {
+        return Vec::new();
+    }
+    let eval = |x: &[f64]| {
+        let v = f(x);
+        if v.is_finite() { v } else { f64::MAX }
+    };
+
+    let mut simplex: Vec<(Vec<f64>, f64)> = Vec::with_capacity(dim + 1);
+    simplex.push((start.to_vec(), eval(start)));
+    for i in 0..dim {
+        let mut vertex = start.to_vec();
+        vertex[i] += step;
+        let value = eval(&vertex);
+        simplex.push((vertex, value));
+    }
+
+    for _ in 0..max_iter {
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let best = simplex[0].1;
+        let worst = simplex[dim].1;
+        if (worst - best).abs() <= 1e-10 * (best.abs() + 1e-10) {
+            break;
+        }
+
+        let centroid: Vec<f64> = (0..dim)
+            .map(|j| simplex[..dim].iter().map(|(x, _)| x[j]).sum::<f64>() / dim as f64)
+            .collect();
+        let towards = |coef: f64| -> Vec<f64> {
+            (0..dim).map(|j| centroid[j] + coef * (simplex[dim].0[j] - centroid[j])).collect()
+        };
+
+        let reflected = towards(-1.0);
+        let reflected_value = eval(&reflected);
+
+        if reflected_value < best {
+            let expanded = towards(-2.0);
+            let expanded_value = eval(&expanded);
+            simplex[dim] = if expanded_value < reflected_value {
+                (expanded, expanded_value)
+            } else {
+                (reflected, reflected_value)
+            };
+        } else if reflected_value < simplex[dim - 1].1 {
+            simplex[dim] = (reflected, reflected_value);
+        } else {
+            let contracted = towards(0.5);
+            let contracted_value = eval(&contracted);
+            if contracted_value < worst {
+                simplex[dim] = (contracted, contracted_value);
+            } else {
+                // Réduction de tout le simplexe vers le meilleur sommet
+                let anchor = simplex[0].0.clone();
+                for vertex in simplex.iter_mut().skip(1) {
+                    let shrunk: Vec<f64> = vertex.0.iter().zip(&anchor).map(|(x, a)| a + 0.5 * (x - a)).collect();
+                    *vertex = (shrunk.clone(), eval(&shrunk));
+                }
+            }
+        }
+    }
+
+    simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+    simplex.swap_remove(0).0
+}
+
+/// Fonction logistique : ramène un réel dans ]0, 1[
+pub fn logistic(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Réciproque de la fonction logistique
+pub fn logit(p: f64) -> f64 {
+    (p / (1.0 - p)).ln()
+}
+
+/// Quantile d'ordre p d'un échantillon déjà trié (interpolation linéaire)
+pub fn quantile_sorted(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = p.clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+    let low = rank.floor() as usize;
+    let high = rank.ceil() as usize;
+    sorted[low] + (sorted[high] - sorted[low]) * (rank - low as f64)
+}
+
+/// Écart-type (échantillon, divisé par n - 1)
+pub fn std_dev(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let m = mean(values);
+    (values.iter().map(|v| (v - m).powi(2)).sum::<f64>() / (values.len() - 1) as f64).sqrt()
+}
+
+/// Rendements logarithmiques ln(x_t / x_{t-1}) ; les prix non positifs sont ignorés
+pub fn log_returns(values: &[f64]) -> Vec<f64> {
+    values
+        .windows(2)
+        .filter(|w| w[0] > 0.0 && w[1] > 0.0)
+        .map(|w| (w[1] / w[0]).ln())
+        .collect()
+}
+
+/// Lissage LOESS (régression linéaire locale à poids tricubes) d'une série régulièrement espacée ;
+/// chaque point est ajusté sur ses `window` plus proches voisins
+pub fn loess(values: &[f64], window: usize) -> Vec<f64> {
+    let n = values.len();
+    let window = window.clamp(2, n.max(2));
+    if n < 3 {
+        return values.to_vec();
+    }
+
+    (0..n)
+        .map(|i| {
+            let start = i.saturating_sub(window / 2).min(n.saturating_sub(window));
+            let end = (start + window).min(n);
+            let radius = (i - start).max(end - 1 - i) as f64 + 1.0;
+
+            let (mut sw, mut swx, mut swy, mut swxx, mut swxy) = (0.0, 0.0, 0.0, 0.0, 0.0);
+            for (j, y) in values.iter().enumerate().take(end).skip(start) {
+                let x = j as f64 - i as f64;
+                let w = (1.0 - (x.abs() / radius).powi(3)).powi(3);
+                sw += w;
+                swx += w * x;
+                swy += w * y;
+                swxx += w * x * x;
+                swxy += w * x * y;
+            }
+
+            // Valeur en x = 0 de la droite pondérée ; moyenne pondérée si elle est dégénérée
+            let denominator = sw * swxx - swx * swx;
+            if denominator.abs() > f64::EPSILON * sw * swxx {
+                (swy * swxx - swx * swxy) / denominator
+            } else {
+                swy / sw
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &[f64], expected: &[f64], tolerance: f64) {
+        assert_eq!(actual.len(), expected.len(), "longueur de {actual:?}");
+        for (i, (a, e)) in actual.iter().zip(expected).enumerate() {
+            assert!((a - e).abs() <= tolerance, "indice {i} : {a} au lieu de {e}");
+        }
+    }
+
+    #[test]
+    fn autocovariances_divide_by_the_series_length() {
+        // Écarts à la moyenne −1,5, −0,5, 0,5, 1,5
+        assert_close(&autocovariances(&[1.0, 2.0, 3.0, 4.0], 2), &[1.25, 0.3125, -0.375], 1e-12);
+    }
+
+    #[test]
+    fn levinson_durbin_recovers_an_ar1_process() {
+        // Autocovariances théoriques d'un AR(1) de coefficient 0,5 et de variance 1
+        let fits = levinson_durbin(&[1.0, 0.5, 0.25], 2);
+        assert_eq!(fits.len(), 3);
+        assert_close(&fits[1].0, &[0.5], 1e-12);
+        assert!((fits[1].1 - 0.75).abs() < 1e-12);
+        // L'ordre 2 n'apporte rien : coefficient partiel nul, même variance de bruit
+        assert_close(&fits[2].0, &[0.5, 0.0], 1e-12);
+        assert!((fits[2].1 - 0.75).abs() < 1e-12);
+    }
+
+    #[test]
+    fn yule_walker_fit_on_a_simulated_ar1() {
+        // x_t = 0,7·x_{t-1} + ε_t, bruit uniforme centré tiré d'un générateur congruentiel fixe
+        let mut state: u64 = 42;
+        let mut x = 0.0;
+        let series: Vec<f64> = (0..5000)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                x = 0.7 * x + ((state >> 11) as f64 / (1u64 << 53) as f64 - 0.5);
+                x
+            })
+            .collect();
+        let fits = levinson_durbin(&autocovariances(&series, 1), 1);
+        assert!((fits[1].0[0] - 0.7).abs() < 0.03, "φ estimé {}", fits[1].0[0]);
+        // Variance du bruit uniforme sur [−0,5, 0,5] : 1/12
+        assert!((fits[1].1 - 1.0 / 12.0).abs() < 0.005, "σ² estimée {}", fits[1].1);
+    }
+
+    #[test]
+    fn normal_quantile_matches_the_tables() {
+        assert!(normal_quantile(0.5).abs() < 1e-9);
+        assert!((normal_quantile(0.975) - 1.959963985).abs() < 1e-8);
+        assert!((normal_quantile(0.01) + 2.326347874).abs() < 1e-8);
+        assert!((normal_quantile(0.999) - 3.090232306).abs() < 1e-8);
+        assert_eq!(normal_quantile(0.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn ols_fits_an_exact_line() {
+        let design: Vec<Vec<f64>> = (0..6).map(|x| vec![1.0, x as f64]).collect();
+        let y: Vec<f64> = (0..6).map(|x| 2.0 + 3.0 * x as f64).collect();
+        let fit = ols(&design, &y).unwrap();
+        assert_close(&fit.coefficients, &[2.0, 3.0], 1e-9);
+        assert_close(&fit.std_errors, &[0.0, 0.0], 1e-6);
+        // Colonnes colinéaires : X'X singulière
+        let collinear: Vec<Vec<f64>> = (0..6).map(|x| vec![x as f64, 2.0 * x as f64]).collect();
+        assert!(ols(&collinear, &y).is_none());
+    }
+
+    #[test]
+    fn psi_weights_expand_arma_models() {
+        // AR(1) : ψ_j = φ^j ; MA(1) : ψ_1 = θ puis 0 ; ARMA(1,1) : ψ_j = φ^(j-1)·(φ + θ)
+        assert_close(&psi_weights(&[0.5], &[], 4), &[1.0, 0.5, 0.25, 0.125], 1e-12);
+        assert_close(&psi_weights(&[], &[0.4], 3), &[1.0, 0.4, 0.0], 1e-12);
+        assert_close(&psi_weights(&[0.5], &[0.4], 3), &[1.0, 0.9, 0.45], 1e-12);
+        assert_close(&forecast_std_devs(&[1.0, 0.5], 4.0), &[2.0, 5f64.sqrt()], 1e-12);
+    }
+
+    #[test]
+    fn nelder_mead_minimises_a_quadratic() {
+        let minimum = nelder_mead(|x| (x[0] - 1.0).powi(2) + 10.0 * (x[1] + 2.0).powi(2), &[0.0, 0.0], 0.5, 1000);
+        assert_close(&minimum, &[1.0, -2.0], 1e-4);
+    }
+
+    #[test]
+    fn loess_reproduces_a_line() {
+        let line: Vec<f64> = (0..10).map(|i| 2.0 + 0.5 * i as f64).collect();
+        assert_close(&loess(&line, 5), &line, 1e-9);
+    }
+}
@@ -1,63 +1,27 @@
+use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 
+/// Insights d'un symbole calculés selon un profil
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StockInsights {
+    /// Profil utilisé
+    pub profile: String,
     pub last_price: Option<f64>,
-    pub day_change: Option<f64>,
-    pub day_change_percent: Option<f64>,
-
-    pub sma_7: Option<f64>,
-    pub sma_30: Option<f64>,
-    pub ema_7: Option<f64>,
-    pub ema_30: Option<f64>,
-
-    pub bollinger_upper: Option<f64>,
-    pub bollinger_lower: Option<f64>,
-
-    pub rsi_14: Option<f64>,
-    pub macd: Option<f64>,
-    pub atr_14: Option<f64>,
-
-    pub max_drawdown_30d: Option<f64>,
     pub trend: Option<String>,
-    pub cumulative_gain_30d: Option<f64>,
-    pub volume_avg_30d: Option<f64>,
-    pub volatility_30d: Option<f64>,
-
-    pub price_vs_sector: Option<f64>,
-    pub alert_overbought: Option<bool>,
-    pub alert_oversold: Option<bool>,
+    /// Dernière valeur de chaque courbe, par spec ("rsi:14") ou spec.courbe ("bollinger:20.upper")
+    pub values: BTreeMap<String, Option<f64>>,
+    /// État de chaque seuil du profil, par nom d'alerte
+    pub alerts: BTreeMap<String, bool>,
 }
 
 impl StockInsights {
-    pub fn new() -> Self {
+    pub fn new(profile: &str) -> Self {
         Self {
+            profile: profile.to_string(),
             last_price: None,
-            day_change: None,
-            day_change_percent: None,
-            sma_7: None,
-            sma_30: None,
-            ema_7: None,
-            ema_30: None,
-            bollinger_upper: None,
-            bollinger_lower: None,
-            rsi_14: None,
-            macd: None,
-            atr_14: None,
-            max_drawdown_30d: None,
             trend: None,
-            cumulative_gain_30d: None,
-            volume_avg_30d: None,
-            volatility_30d: None,
-            price_vs_sector: None,
-            alert_overbought: None,
-            alert_oversold: None,
+            values: BTreeMap::new(),
+            alerts: BTreeMap::new(),
         }
     }
 }
-
-impl Default for StockInsights {
-    fn default() -> Self {
-        Self::new()
-    }
-}
\ No newline at end of file
@@ -1,64 +1,143 @@
-use crate::domain::indicators;
+use crate::domain::indicator_series::IndicatorSpec;
+use crate::domain::indicator_stream::StreamingIndicator;
+use crate::domain::insight_profile::{InsightProfile, InsightThreshold};
 use crate::domain::stock_insights::StockInsights;
-use crate::domain::time_series::StockSegment;
-use std::time::Instant;
-
-pub struct StockInsightsBuilder;
+use crate::domain::time_series::StockPoint;
+use chrono::{DateTime, Utc};
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// Indicateur d'un profil et son calcul incrémental
+struct StreamedIndicator {
+    name: String,
+    thresholds: Vec<InsightThreshold>,
+    stream: Box<dyn StreamingIndicator>,
+}
 
-impl StockInsightsBuilder {
-    pub fn build(historical_segments: &[StockSegment]) -> StockInsights {
-        let start = Instant::now();
+/// Insights d'un profil tenus à jour point par point : un nouveau point coûte O(1) par indicateur,
+/// quelle que soit la longueur de l'historique déjà intégré
+pub struct StockInsightsBuilder {
+    profile: InsightProfile,
+    indicators: Vec<StreamedIndicator>,
+    first_close: Option<f64>,
+    last_point: Option<StockPoint>,
+    /// Points reçus jusqu'au dernier point intégré inclus, doublons compris
+    consumed: usize,
+    /// Empreinte de ces points, indépendante de leur ordre
+    fingerprint: u64,
+}
 
-        let mut insights = StockInsights::new();
+/// Empreinte d'un point ; sommée sur un ensemble de points, toute révision la modifie
+fn point_hash(point: &StockPoint) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    point.timestamp.hash(&mut hasher);
+    for value in [point.open, point.high, point.low, point.close, point.volume] {
+        value.to_bits().hash(&mut hasher);
+    }
+    hasher.finish()
+}
 
-        let mut points = historical_segments
+impl StockInsightsBuilder {
+    pub fn new(profile: &InsightProfile) -> Self {
+        let indicators = profile
+            .indicators
             .iter()
-            .flat_map(|s| s.data_points.clone())
-            .collect::<Vec<_>>();
-
-        if points.is_empty() {
-            return insights;
-        }
-
-        points.sort_by_key(|p| p.timestamp);
-
-        insights.last_price = Some(indicators::last_close(&points));
-        insights.day_change = Some(indicators::day_change(&points));
-        insights.day_change_percent = indicators::day_change_percent(&points);
-
-        insights.sma_7 = indicators::sma(&points, 7);
-        insights.sma_30 = indicators::sma(&points, 30);
-
-        insights.ema_7 = indicators::ema(&points, 7);
-        insights.ema_30 = indicators::ema(&points, 30);
-
-        let (upper, lower) = indicators::bollinger(&points, 20);
-        insights.bollinger_upper = upper;
-        insights.bollinger_lower = lower;
-
-        insights.rsi_14 = indicators::rsi(&points, 14);
-
-        insights.macd = indicators::macd(&points);
+            .filter_map(|indicator| match IndicatorSpec::parse(&indicator.spec) {
+                Ok(spec) => Some(StreamedIndicator {
+                    name: spec.name(),
+                    thresholds: indicator.thresholds.clone(),
+                    stream: spec.stream(),
+                }),
+                Err(err) => {
+                    eprintln!("Profil '{}' : indicateur ignoré ({})", profile.name, err);
+                    None
+                }
+            })
+            .collect();
+
+        Self { profile: profile.clone(), indicators, first_close: None, last_point: None, consumed: 0, fingerprint: 0 }
+    }
 
-        insights.atr_14 = indicators::atr(&points, 14);
+    pub fn profile(&self) -> &InsightProfile {
+        &self.profile
+    }
 
-        insights.max_drawdown_30d = indicators::max_drawdown(&points, 30);
+    pub fn last_timestamp(&self) -> Option<DateTime<Utc>> {
+        self.last_point.as_ref().map(|p| p.timestamp)
+    }
 
-        insights.trend = indicators::trend(&points);
+    /// Intègre le point suivant, en ordre chronologique. Un doublon de la date du dernier point
+    /// est compté dans l'empreinte mais pas intégré (false) ; un point antérieur est ignoré.
+    pub fn push(&mut self, point: &StockPoint) -> bool {
+        match self.last_timestamp() {
+            Some(last) if point.timestamp < last => return false,
+            Some(last) if point.timestamp == last => {
+                self.account(point);
+                return false;
+            }
+            _ => {}
+        }
+        for indicator in &mut self.indicators {
+            indicator.stream.push(point);
+        }
+        self.first_close.get_or_insert(point.close);
+        self.last_point = Some(point.clone());
+        self.account(point);
+        true
+    }
 
-        insights.cumulative_gain_30d = indicators::cumulative_gain(&points, 30);
+    fn account(&mut self, point: &StockPoint) {
+        self.consumed += 1;
+        self.fingerprint = self.fingerprint.wrapping_add(point_hash(point));
+    }
 
-        insights.volume_avg_30d = indicators::volume_avg(&points, 30);
-        insights.volatility_30d = indicators::volatility(&points, 30);
+    pub fn extend<'a>(&mut self, points: impl IntoIterator<Item = &'a StockPoint>) {
+        for point in points {
+            self.push(point);
+        }
+    }
 
-        insights.price_vs_sector = Some(insights.last_price.unwrap_or(0.0) / 100.0);
+    /// Vrai si `points` (dans un ordre quelconque) prolonge l'historique intégré : les points
+    /// jusqu'à la date du dernier point intégré sont exactement ceux déjà reçus, sans révision,
+    /// ajout ni retrait
+    pub fn continues<'a>(&self, points: impl IntoIterator<Item = &'a StockPoint>) -> bool {
+        let Some(last) = self.last_timestamp() else { return true };
+        let (count, fingerprint) = points
+            .into_iter()
+            .filter(|p| p.timestamp <= last)
+            .fold((0, 0u64), |(count, fingerprint), p| (count + 1, fingerprint.wrapping_add(point_hash(p))));
+        count == self.consumed && fingerprint == self.fingerprint
+    }
 
-        if let Some(rsi) = insights.rsi_14 {
-            insights.alert_overbought = Some(rsi > 70.0);
-            insights.alert_oversold = Some(rsi < 30.0);
+    /// Valeurs et alertes au dernier point intégré
+    pub fn build(&self) -> StockInsights {
+        let mut insights = StockInsights::new(&self.profile.name);
+        let Some(last) = &self.last_point else { return insights };
+
+        insights.last_price = Some(last.close);
+        insights.trend = self.first_close.map(|first| {
+            if last.close >= first { "Haussier".to_string() } else { "Baissier".to_string() }
+        });
+
+        for indicator in &self.indicators {
+            let lines = indicator.stream.current();
+            let single = lines.len() == 1;
+            for (line, value) in &lines {
+                let key = if single { indicator.name.clone() } else { format!("{}.{}", indicator.name, line) };
+                insights.values.insert(key, *value);
+            }
+
+            for threshold in &indicator.thresholds {
+                // Sans courbe précisée, la seule courbe de l'indicateur
+                let value = match &threshold.line {
+                    Some(line) => lines.get(line.as_str()).copied().flatten(),
+                    None if single => lines.values().next().copied().flatten(),
+                    None => None,
+                };
+                let Some(value) = value else { continue };
+                let triggered = threshold.triggered(value);
+                *insights.alerts.entry(threshold.name.clone()).or_insert(false) |= triggered;
+            }
         }
-        println!("StockInsightsBuilder: calcul terminé en {:?}", start.elapsed());
-
         insights
     }
 }
@@ -1,5 +1,5 @@
 use serde::{Serialize, Deserialize};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Timelike, Utc};
 
 /// Niveau le plus fin : un point de données temporel
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +23,58 @@ pub enum TimeInterval {
     Month,
 }
 
+impl TimeInterval {
+    /// Rang de finesse : plus il est petit, plus la granularité est fine
+    pub fn rank(&self) -> u8 {
+        match self {
+            TimeInterval::Tick => 0,
+            TimeInterval::Minute => 1,
+            TimeInterval::Hour => 2,
+            TimeInterval::Day => 3,
+            TimeInterval::Week => 4,
+            TimeInterval::Month => 5,
+        }
+    }
+
+    /// Clé du regroupement d'un horodatage dans un intervalle de cette granularité
+    fn bucket(&self, ts: &DateTime<Utc>) -> (i32, u32, u32, u32) {
+        match self {
+            TimeInterval::Tick => (ts.year(), ts.ordinal(), ts.num_seconds_from_midnight(), ts.timestamp_subsec_nanos()),
+            TimeInterval::Minute => (ts.year(), ts.ordinal(), ts.hour(), ts.minute()),
+            TimeInterval::Hour => (ts.year(), ts.ordinal(), ts.hour(), 0),
+            TimeInterval::Day => (ts.year(), ts.ordinal(), 0, 0),
+            TimeInterval::Week => (ts.iso_week().year(), ts.iso_week().week(), 0, 0),
+            TimeInterval::Month => (ts.year(), ts.month(), 0, 0),
+        }
+    }
+}
+
+/// Agrège des points triés vers une granularité plus grossière (OHLCV classique) ;
+/// chaque bougie agrégée porte l'horodatage de son dernier point
+pub fn resample(points: &[StockPoint], interval: &TimeInterval) -> Vec<StockPoint> {
+    let mut out: Vec<StockPoint> = Vec::new();
+    let mut current_key = None;
+
+    for p in points {
+        let key = interval.bucket(&p.timestamp);
+        match out.last_mut() {
+            Some(candle) if current_key == Some(key) => {
+                candle.timestamp = p.timestamp;
+                candle.high = candle.high.max(p.high);
+                candle.low = candle.low.min(p.low);
+                candle.close = p.close;
+                candle.volume += p.volume;
+            }
+            _ => {
+                out.push(p.clone());
+                current_key = Some(key);
+            }
+        }
+    }
+
+    out
+}
+
 /// Un segment temporel contigu de données
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StockSegment {
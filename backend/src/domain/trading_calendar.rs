@@ -0,0 +1,272 @@
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use crate::domain::time_series::TimeInterval;
+
+/// Calendrier de cotation du NYSE : week-ends, jours fériés et séance 9h30-16h (heure de New York)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TradingCalendar;
+
+impl TradingCalendar {
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !is_nyse_holiday(date)
+    }
+
+    /// Premier jour de cotation strictement postérieur à `date`
+    pub fn next_trading_day(&self, date: NaiveDate) -> NaiveDate {
+        let mut next = date.succ_opt().unwrap_or(date);
+        while !self.is_trading_day(next) {
+            next = next.succ_opt().unwrap_or(next);
+        }
+        next
+    }
+
+    /// Horodatages des `count` prochaines séances valides après `last`,
+    /// espacées de `step` intervalles de granularité `interval`
+    pub fn next_sessions(&self, last: DateTime<Utc>, interval: &TimeInterval, step: u32, count: usize) -> Vec<DateTime<Utc>> {
+        let step = step.max(1);
+        let mut current = last;
+        let mut out = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            for _ in 0..step {
+                current = self.advance(current, interval);
+            }
+            out.push(current);
+        }
+
+        out
+    }
+
+    /// Avance d'un intervalle en restant sur une séance ouverte
+    fn advance(&self, ts: DateTime<Utc>, interval: &TimeInterval) -> DateTime<Utc> {
+        match interval {
+            TimeInterval::Tick | TimeInterval::Minute => self.advance_intraday(ts, Duration::minutes(1)),
+            TimeInterval::Hour => self.advance_intraday(ts, Duration::hours(1)),
+            TimeInterval::Day => {
+                let date = self.next_trading_day(ts.date_naive());
+                date.and_time(ts.time()).and_utc()
+            }
+            TimeInterval::Week => self.roll_forward(ts + Duration::weeks(1)),
+            TimeInterval::Month => self.roll_forward(ts.checked_add_months(Months::new(1)).unwrap_or(ts)),
+        }
+    }
+
+    /// Reporte un horodatage journalier au prochain jour ouvré s'il tombe sur un jour fermé
+    fn roll_forward(&self, ts: DateTime<Utc>) -> DateTime<Utc> {
+        if self.is_trading_day(ts.date_naive()) {
+            ts
+        } else {
+            self.next_trading_day(ts.date_naive()).and_time(ts.time()).and_utc()
+        }
+    }
+
+    fn advance_intraday(&self, ts: DateTime<Utc>, delta: Duration) -> DateTime<Utc> {
+        let candidate = ts + delta;
+        let offset = new_york_offset(candidate.date_naive());
+        let local = candidate + offset;
+        let date = local.date_naive();
+
+        if self.is_trading_day(date) {
+            if local.time() < session_open() {
+                return session_open_utc(date);
+            }
+            if local.time() < session_close() {
+                return candidate;
+            }
+        }
+
+        session_open_utc(self.next_trading_day(date))
+    }
+}
+
+fn session_open() -> NaiveTime {
+    NaiveTime::from_hms_opt(9, 30, 0).unwrap()
+}
+
+fn session_close() -> NaiveTime {
+    NaiveTime::from_hms_opt(16, 0, 0).unwrap()
+}
+
+/// Ouverture de la séance du jour `date`, exprimée en UTC
+fn session_open_utc(date: NaiveDate) -> DateTime<Utc> {
+    Utc.from_utc_datetime(&date.and_time(session_open())) - new_york_offset(date)
+}
+
+/// Décalage de New York par rapport à UTC : -4h en heure d'été
+/// (du 2e dimanche de mars au 1er dimanche de novembre), -5h sinon
+fn new_york_offset(date: NaiveDate) -> Duration {
+    let year = date.year();
+    let dst_start = nth_weekday(year, 3, Weekday::Sun, 2);
+    let dst_end = nth_weekday(year, 11, Weekday::Sun, 1);
+    if date >= dst_start && date < dst_end {
+        Duration::hours(-4)
+    } else {
+        Duration::hours(-5)
+    }
+}
+
+/// n-ième occurrence d'un jour de la semaine dans un mois
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n).unwrap()
+}
+
+/// Dernière occurrence d'un jour de la semaine dans un mois
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, 5)
+        .unwrap_or_else(|| nth_weekday(year, month, weekday, 4))
+}
+
+/// Dimanche de Pâques (algorithme grégorien anonyme)
+fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap()
+}
+
+/// Jour férié fixe reporté au vendredi s'il tombe un samedi, au lundi s'il tombe un dimanche
+fn observed(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date - Duration::days(1),
+        Weekday::Sun => date + Duration::days(1),
+        _ => date,
+    }
+}
+
+fn is_nyse_holiday(date: NaiveDate) -> bool {
+    let year = date.year();
+    let fixed = |month: u32, day: u32| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+    // Le Nouvel An tombant un samedi n'est pas reporté au vendredi précédent
+    let new_year = fixed(1, 1);
+    if date == new_year && date.weekday() != Weekday::Sat {
+        return true;
+    }
+    if date == fixed(1, 2) && new_year.weekday() == Weekday::Sun {
+        return true;
+    }
+
+    let mut holidays = vec![
+        nth_weekday(year, 1, Weekday::Mon, 3),
+        nth_weekday(year, 2, Weekday::Mon, 3),
+        easter_sunday(year) - Duration::days(2),
+        last_weekday(year, 5, Weekday::Mon),
+        observed(fixed(7, 4)),
+        nth_weekday(year, 9, Weekday::Mon, 1),
+        nth_weekday(year, 11, Weekday::Thu, 4),
+        observed(fixed(12, 25)),
+    ];
+    if year >= 2022 {
+        holidays.push(observed(fixed(6, 19)));
+    }
+
+    holidays.contains(&date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn easter_matches_known_dates() {
+        assert_eq!(easter_sunday(2000), date(2000, 4, 23));
+        assert_eq!(easter_sunday(2019), date(2019, 4, 21));
+        assert_eq!(easter_sunday(2024), date(2024, 3, 31));
+        assert_eq!(easter_sunday(2025), date(2025, 4, 20));
+    }
+
+    #[test]
+    fn known_holidays_are_closed() {
+        let calendar = TradingCalendar;
+        for closed in [
+            date(2024, 1, 1),   // Nouvel An
+            date(2024, 1, 15),  // Martin Luther King
+            date(2024, 2, 19),  // Presidents' Day
+            date(2024, 3, 29),  // Vendredi saint
+            date(2024, 5, 27),  // Memorial Day
+            date(2024, 6, 19),  // Juneteenth
+            date(2024, 7, 4),   // Fête nationale
+            date(2024, 9, 2),   // Labor Day
+            date(2024, 11, 28), // Thanksgiving
+            date(2024, 12, 25), // Noël
+        ] {
+            assert!(!calendar.is_trading_day(closed), "{} devrait être fermé", closed);
+        }
+        assert!(calendar.is_trading_day(date(2024, 3, 28)));
+        assert!(calendar.is_trading_day(date(2024, 4, 1)));
+        // Juneteenth n'est chômé que depuis 2022
+        assert!(calendar.is_trading_day(date(2021, 6, 18)));
+    }
+
+    #[test]
+    fn weekend_holidays_are_observed_on_the_nearest_weekday() {
+        let calendar = TradingCalendar;
+        // 4 juillet un samedi : fermé le vendredi ; un dimanche : fermé le lundi
+        assert!(!calendar.is_trading_day(date(2020, 7, 3)));
+        assert!(!calendar.is_trading_day(date(2021, 7, 5)));
+        assert!(!calendar.is_trading_day(date(2026, 7, 3)));
+        // Noël et Juneteenth un dimanche
+        assert!(!calendar.is_trading_day(date(2022, 12, 26)));
+        assert!(!calendar.is_trading_day(date(2022, 6, 20)));
+        // Nouvel An un dimanche : reporté au lundi ; un samedi : pas de report au vendredi
+        assert!(!calendar.is_trading_day(date(2023, 1, 2)));
+        assert!(calendar.is_trading_day(date(2021, 12, 31)));
+    }
+
+    #[test]
+    fn session_open_follows_new_york_daylight_saving_time() {
+        // Heure d'été du 10 mars au 3 novembre 2024
+        assert_eq!(session_open_utc(date(2024, 3, 8)), utc(2024, 3, 8, 14, 30));
+        assert_eq!(session_open_utc(date(2024, 3, 11)), utc(2024, 3, 11, 13, 30));
+        assert_eq!(session_open_utc(date(2024, 11, 1)), utc(2024, 11, 1, 13, 30));
+        assert_eq!(session_open_utc(date(2024, 11, 4)), utc(2024, 11, 4, 14, 30));
+    }
+
+    #[test]
+    fn minute_bars_roll_over_the_close_and_the_weekend() {
+        let calendar = TradingCalendar;
+        // Vendredi 15h59 (heure d'hiver), lundi en heure d'été
+        let next = calendar.next_sessions(utc(2024, 3, 8, 20, 58), &TimeInterval::Minute, 1, 3);
+        assert_eq!(next, vec![utc(2024, 3, 8, 20, 59), utc(2024, 3, 11, 13, 30), utc(2024, 3, 11, 13, 31)]);
+        // Avant l'ouverture : première barre à l'ouverture
+        let next = calendar.next_sessions(utc(2024, 3, 11, 12, 0), &TimeInterval::Minute, 1, 1);
+        assert_eq!(next, vec![utc(2024, 3, 11, 13, 30)]);
+    }
+
+    #[test]
+    fn hourly_bars_skip_holidays() {
+        let calendar = TradingCalendar;
+        // Mercredi 3 juillet 2024 15h30 (heure d'été) : le 4 est fermé, reprise le vendredi 5
+        let next = calendar.next_sessions(utc(2024, 7, 3, 19, 30), &TimeInterval::Hour, 1, 2);
+        assert_eq!(next, vec![utc(2024, 7, 5, 13, 30), utc(2024, 7, 5, 14, 30)]);
+    }
+
+    #[test]
+    fn daily_bars_skip_weekends_and_holidays() {
+        let calendar = TradingCalendar;
+        // Jeudi 28 mars 2024 : vendredi saint puis week-end
+        let next = calendar.next_sessions(utc(2024, 3, 28, 20, 0), &TimeInterval::Day, 1, 2);
+        assert_eq!(next, vec![utc(2024, 4, 1, 20, 0), utc(2024, 4, 2, 20, 0)]);
+        // Pas de 2 : un jour ouvré sur deux
+        let next = calendar.next_sessions(utc(2024, 3, 28, 20, 0), &TimeInterval::Day, 2, 1);
+        assert_eq!(next, vec![utc(2024, 4, 2, 20, 0)]);
+    }
+}
@@ -0,0 +1,36 @@
+use std::collections::BTreeMap;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Stratégie d'exploration de l'espace des hyperparamètres
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchStrategy {
+    /// Produit cartésien de quelques valeurs par paramètre
+    Grid,
+    /// Tirages indépendants dans les bornes de chaque paramètre
+    Random,
+}
+
+/// Une combinaison évaluée en walk-forward
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TuningTrial {
+    /// Paramètres essayés ; vide pour les valeurs par défaut de la méthode
+    pub parameters: BTreeMap<String, f64>,
+    pub folds: usize,
+    pub rmse: Option<f64>,
+    pub mae: Option<f64>,
+}
+
+/// Résultat d'une recherche d'hyperparamètres pour un symbole et une méthode
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TuningResult {
+    pub symbol: String,
+    pub method: String,
+    pub strategy: SearchStrategy,
+    pub best_parameters: BTreeMap<String, f64>,
+    pub best_rmse: Option<f64>,
+    /// Combinaisons évaluées, de la meilleure à la moins bonne
+    pub trials: Vec<TuningTrial>,
+    pub tuned_at: DateTime<Utc>,
+}
@@ -2,14 +2,23 @@ use crate::domain::stock_summary::StockSummary;
 use crate::domain::generic_stock_data_dto::GenericStockDataDTO;
 use crate::domain::utils::can_be_symbol;
 use crate::application::stock_repository::StockRepository;
+use crate::application::tuning_repository::TuningRepository;
+use crate::application::forecast_repository::ForecastRepository;
+use crate::domain::forecast_record::ForecastRecord;
+use crate::application::insight_profile_repository::InsightProfileRepository;
+use crate::domain::insight_profile::InsightProfile;
+use crate::domain::tuning::TuningResult;
 use anyhow::Result;
 use futures::TryStreamExt;
 use async_trait::async_trait;
-use mongodb::{bson::{doc, Regex}, Client, Collection};
+use mongodb::{bson::{doc, Regex}, Client, Collection, IndexModel};
 
 pub struct MongoStockManager {
     summary_collection: Collection<StockSummary>,
     data_collection: Collection<GenericStockDataDTO>,
+    tuning_collection: Collection<TuningResult>,
+    forecast_collection: Collection<ForecastRecord>,
+    profile_collection: Collection<InsightProfile>,
 }
 
 #[async_trait]
@@ -42,6 +51,101 @@ impl StockRepository for MongoStockManager {
     }
 }
 
+#[async_trait]
+impl TuningRepository for MongoStockManager {
+    async fn get_tuning(&self, symbol: &str, method: &str) -> Result<Option<TuningResult>> {
+        let filter = doc! { "symbol": symbol, "method": method };
+        Ok(self.tuning_collection.find_one(filter).await?)
+    }
+
+    async fn list_tunings(&self, symbol: &str) -> Result<Vec<TuningResult>> {
+        let cursor = self.tuning_collection.find(doc! { "symbol": symbol }).await?;
+        Ok(cursor.try_collect().await?)
+    }
+
+    async fn save_tuning(&self, result: &TuningResult) -> Result<()> {
+        let filter = doc! { "symbol": &result.symbol, "method": &result.method };
+        self.tuning_collection.replace_one(filter, result).upsert(true).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ForecastRepository for MongoStockManager {
+    async fn save_forecast(&self, record: &ForecastRecord) -> Result<()> {
+        let filter = doc! { "_id": &record.id };
+        self.forecast_collection.replace_one(filter, record).upsert(true).await?;
+        Ok(())
+    }
+
+    async fn pending_forecasts(&self, after: Option<&str>, limit: usize) -> Result<Vec<ForecastRecord>> {
+        let mut filter = doc! { "complete": false };
+        if let Some(after) = after {
+            filter.insert("_id", doc! { "$gt": after });
+        }
+        let cursor = self
+            .forecast_collection
+            .find(filter)
+            .sort(doc! { "_id": 1 })
+            .limit(limit as i64)
+            .await?;
+        Ok(cursor.try_collect().await?)
+    }
+
+    async fn list_forecasts(&self, symbol: &str, method: Option<&str>, offset: usize, limit: usize) -> Result<Vec<ForecastRecord>> {
+        let mut filter = doc! { "symbol": symbol };
+        if let Some(method) = method {
+            filter.insert("method", method);
+        }
+        // Les plus récentes d'abord
+        let cursor = self
+            .forecast_collection
+            .find(filter)
+            .sort(doc! { "created_at": -1 })
+            .skip(offset as u64)
+            .limit(limit as i64)
+            .await?;
+        Ok(cursor.try_collect().await?)
+    }
+
+    async fn realised_forecasts(&self, symbol: Option<&str>, limit: usize) -> Result<Vec<ForecastRecord>> {
+        let mut filter = doc! { "actuals.0": { "$exists": true } };
+        if let Some(symbol) = symbol {
+            filter.insert("symbol", symbol);
+        }
+        let cursor = self
+            .forecast_collection
+            .find(filter)
+            .sort(doc! { "created_at": -1 })
+            .limit(limit as i64)
+            .await?;
+        Ok(cursor.try_collect().await?)
+    }
+}
+
+#[async_trait]
+impl InsightProfileRepository for MongoStockManager {
+    async fn get_profile(&self, name: &str, owner: Option<&str>) -> Result<Option<InsightProfile>> {
+        let filter = doc! { "name": name, "owner": owner };
+        Ok(self.profile_collection.find_one(filter).await?)
+    }
+
+    async fn list_profiles(&self, owner: Option<&str>) -> Result<Vec<InsightProfile>> {
+        let filter = match owner {
+            Some(owner) => doc! { "$or": [{ "owner": null }, { "owner": owner }] },
+            None => doc! { "owner": null },
+        };
+        let cursor = self.profile_collection.find(filter).await?;
+        Ok(cursor.try_collect().await?)
+    }
+
+    async fn save_profile(&self, profile: &InsightProfile) -> Result<()> {
+        let filter = doc! { "name": &profile.name, "owner": profile.owner.as_deref() };
+        self.profile_collection.replace_one(filter, profile).upsert(true).await?;
+        Ok(())
+    }
+}
+
 impl MongoStockManager {
     pub async fn new(uri: &str, db_name: &str) -> Result<Self> {
         let client = Client::with_uri_str(uri).await?;
@@ -49,16 +153,47 @@ impl MongoStockManager {
 
         let summary_collection = db.collection::<StockSummary>("summaries");
         let data_collection = db.collection::<GenericStockDataDTO>("stock_data");
+        let tuning_collection = db.collection::<TuningResult>("tuning_results");
+        let forecast_collection = db.collection::<ForecastRecord>("forecasts");
+        let profile_collection = db.collection::<InsightProfile>("insight_profiles");
 
         db.run_command(doc! { "ping": 1 }).await?;
         println!("Connexion MongoDB OK (db = {db_name})");
 
+        Self::prepare_forecasts(&forecast_collection).await?;
+
         Ok(Self {
             summary_collection,
             data_collection,
+            tuning_collection,
+            forecast_collection,
+            profile_collection,
         })
     }
 
+    /// Convertit les dates de création archivées en chaînes (anciens enregistrements) en dates BSON,
+    /// puis crée les index des requêtes d'évaluation, de consultation et de classement
+    async fn prepare_forecasts(collection: &Collection<ForecastRecord>) -> Result<()> {
+        let migrated = collection
+            .update_many(
+                doc! { "created_at": { "$type": "string" } },
+                vec![doc! { "$set": { "created_at": { "$toDate": "$created_at" } } }],
+            )
+            .await?;
+        if migrated.modified_count > 0 {
+            println!("Prévisions archivées : {} dates de création converties", migrated.modified_count);
+        }
+
+        collection
+            .create_indexes([
+                IndexModel::builder().keys(doc! { "complete": 1, "_id": 1 }).build(),
+                IndexModel::builder().keys(doc! { "symbol": 1, "created_at": -1 }).build(),
+                IndexModel::builder().keys(doc! { "created_at": -1 }).build(),
+            ])
+            .await?;
+        Ok(())
+    }
+
     pub async fn add_stock(&self, stock: StockSummary) -> Result<()> {
         self.summary_collection.insert_one(stock).await?;
         Ok(())
@@ -0,0 +1,278 @@
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    response::Json,
+    routing::get,
+    Router,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use crate::application::predicators::prediction_config::{DEFAULT_HORIZON, MAX_HORIZON};
+use crate::application::stock_manager::StockManager;
+use crate::domain::decomposition::{self, Decomposition, Seasonality};
+use crate::domain::garch::GarchModel;
+use crate::domain::indicator_series::{IndicatorChart, IndicatorSpec};
+use crate::domain::regime::{self, RegimeAnalysis};
+use crate::domain::statistics;
+use crate::domain::time_series::TimeInterval;
+use crate::domain::trading_calendar::TradingCalendar;
+use crate::interfaces::stock_handler::{load_points, load_points_at_least, StoredHistory};
+
+// --- QUERY STRUCTS ---
+#[derive(Deserialize)]
+pub struct VolatilityQuery {
+    horizon: Option<usize>,
+}
+
+#[derive(Deserialize)]
+pub struct RegimeQuery {
+    /// Nombre d'états cachés : 2 (haussier / baissier) ou 3 (+ forte volatilité)
+    states: Option<usize>,
+}
+
+#[derive(Deserialize)]
+pub struct DecompositionQuery {
+    /// Saisonnalités séparées par des virgules ("weekly,yearly") ; détectées selon l'historique si absent
+    seasonalities: Option<String>,
+    /// Fenêtre LOESS de la tendance, en nombre de points
+    trend_window: Option<usize>,
+}
+
+#[derive(Deserialize)]
+pub struct IndicatorQuery {
+    /// Indicateurs séparés par des virgules, avec période facultative ("sma:20,rsi:14,macd")
+    names: Option<String>,
+    /// Granularité de calcul ; l'historique stocké est agrégé si elle est plus grossière
+    interval: Option<TimeInterval>,
+}
+
+/// Indicateurs renvoyés lorsque la requête n'en précise aucun
+const DEFAULT_INDICATORS: &str = "sma:20,bollinger:20,rsi:14";
+
+/// Nombre maximal d'indicateurs distincts par requête
+const MAX_INDICATOR_SPECS: usize = 20;
+
+#[derive(Serialize)]
+pub struct VolatilityPointResponse {
+    timestamp: DateTime<Utc>,
+    /// Volatilité conditionnelle prévue sur un intervalle
+    volatility: f64,
+    /// Volatilité cumulée depuis le dernier point connu
+    cumulative_volatility: f64,
+}
+
+#[derive(Serialize)]
+pub struct VolatilityResponse {
+    symbol: String,
+    omega: f64,
+    alpha: f64,
+    beta: f64,
+    persistence: f64,
+    log_likelihood: f64,
+    long_run_volatility: f64,
+    /// Volatilité conditionnelle du dernier rendement observé
+    current_volatility: f64,
+    forecast: Vec<VolatilityPointResponse>,
+}
+
+#[derive(Serialize)]
+pub struct RegimeResponse {
+    symbol: String,
+    #[serde(flatten)]
+    analysis: RegimeAnalysis,
+}
+
+#[derive(Serialize)]
+pub struct IndicatorResponse {
+    symbol: String,
+    interval: TimeInterval,
+    #[serde(flatten)]
+    chart: IndicatorChart,
+}
+
+#[derive(Serialize)]
+pub struct DecompositionResponse {
+    symbol: String,
+    interval: TimeInterval,
+    #[serde(flatten)]
+    decomposition: Decomposition,
+}
+
+// ---- ROUTER ----
+pub fn analysis_router(stock_manager: Arc<StockManager>) -> Router {
+    Router::new()
+        .route("/stocks/:symbol/volatility", get(get_volatility))
+        .route("/stocks/:symbol/regimes", get(get_regimes))
+        .route("/stocks/:symbol/decomposition", get(get_decomposition))
+        .route("/stocks/:symbol/indicators", get(get_indicators))
+        .layer(Extension(stock_manager))
+}
+
+// ---- HANDLERS ----
+/// Prévision de volatilité GARCH(1,1) sur les rendements close-to-close
+async fn get_volatility(
+    Path(symbol): Path<String>,
+    Query(query): Query<VolatilityQuery>,
+    Extension(stock_manager): Extension<Arc<StockManager>>,
+) -> Result<Json<VolatilityResponse>, (StatusCode, String)> {
+    let horizon = query.horizon.unwrap_or(DEFAULT_HORIZON);
+    if horizon == 0 || horizon > MAX_HORIZON {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Horizon invalide : {} (attendu entre 1 et {})", horizon, MAX_HORIZON),
+        ));
+    }
+
+    let StoredHistory { dto, interval, points } = load_points(&stock_manager, &symbol, None).await?;
+    let closes: Vec<f64> = points.iter().map(|p| p.close).collect();
+
+    let Some(model) = GarchModel::fit(&statistics::log_returns(&closes)) else {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Historique insuffisant pour estimer un GARCH ({} points)", points.len()),
+        ));
+    };
+
+    let last = points.last().map(|p| p.timestamp).unwrap_or_else(Utc::now);
+    let timestamps = TradingCalendar.next_sessions(last, &interval, 1, horizon);
+
+    let mut cumulative = 0.0;
+    let forecast = model
+        .forecast_variances(horizon)
+        .into_iter()
+        .zip(timestamps)
+        .map(|(variance, timestamp)| {
+            cumulative += variance;
+            VolatilityPointResponse {
+                timestamp,
+                volatility: variance.sqrt(),
+                cumulative_volatility: cumulative.sqrt(),
+            }
+        })
+        .collect();
+
+    Ok(Json(VolatilityResponse {
+        symbol: dto.symbol.clone(),
+        omega: model.omega,
+        alpha: model.alpha,
+        beta: model.beta,
+        persistence: model.persistence(),
+        log_likelihood: model.log_likelihood,
+        long_run_volatility: model.long_run_variance().sqrt(),
+        current_volatility: model.conditional_variances.last().copied().unwrap_or(0.0).sqrt(),
+        forecast,
+    }))
+}
+
+/// Régimes de marché (HMM gaussien sur les rendements journaliers)
+async fn get_regimes(
+    Path(symbol): Path<String>,
+    Query(query): Query<RegimeQuery>,
+    Extension(stock_manager): Extension<Arc<StockManager>>,
+) -> Result<Json<RegimeResponse>, (StatusCode, String)> {
+    let states = query.states.unwrap_or(3);
+    if !(2..=3).contains(&states) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Nombre d'états invalide : {} (attendu 2 ou 3)", states),
+        ));
+    }
+
+    let StoredHistory { dto, points, .. } = load_points_at_least(&stock_manager, &symbol, &TimeInterval::Day).await?;
+    let Some(analysis) = regime::detect_regimes(&points, states) else {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Historique insuffisant pour estimer un HMM à {} états", states),
+        ));
+    };
+
+    Ok(Json(RegimeResponse {
+        symbol: dto.symbol.clone(),
+        analysis,
+    }))
+}
+
+/// Décomposition tendance / saisonnalités / résidu des cours de clôture, à granularité journalière au plus fin
+async fn get_decomposition(
+    Path(symbol): Path<String>,
+    Query(query): Query<DecompositionQuery>,
+    Extension(stock_manager): Extension<Arc<StockManager>>,
+) -> Result<Json<DecompositionResponse>, (StatusCode, String)> {
+    let StoredHistory { dto, interval, points } = load_points_at_least(&stock_manager, &symbol, &TimeInterval::Day).await?;
+
+    let mut seasonalities = match query.seasonalities.as_deref() {
+        Some(raw) => raw
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| match s.to_ascii_lowercase().as_str() {
+                "weekly" => Ok(Seasonality::Weekly),
+                "yearly" => Ok(Seasonality::Yearly),
+                _ => Err((StatusCode::BAD_REQUEST, format!("Saisonnalité inconnue : {} (weekly ou yearly)", s))),
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        None => Seasonality::detectable(&points, &interval),
+    };
+    let mut seen = Vec::new();
+    seasonalities.retain(|s| {
+        let first = !seen.contains(s);
+        seen.push(*s);
+        first
+    });
+
+    let trend_window = query
+        .trend_window
+        .unwrap_or_else(|| decomposition::default_trend_window(&points, &seasonalities));
+    if trend_window < 3 {
+        return Err((StatusCode::BAD_REQUEST, "La fenêtre de tendance doit être au moins 3".to_string()));
+    }
+
+    let Some(decomposition) = decomposition::decompose(&points, &seasonalities, trend_window) else {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Historique insuffisant pour une décomposition ({} points)", points.len()),
+        ));
+    };
+
+    Ok(Json(DecompositionResponse {
+        symbol: dto.symbol.clone(),
+        interval,
+        decomposition,
+    }))
+}
+
+/// Séries complètes des indicateurs techniques, alignées sur les points de l'historique
+async fn get_indicators(
+    Path(symbol): Path<String>,
+    Query(query): Query<IndicatorQuery>,
+    Extension(stock_manager): Extension<Arc<StockManager>>,
+) -> Result<Json<IndicatorResponse>, (StatusCode, String)> {
+    let specs = IndicatorSpec::parse_list(query.names.as_deref().unwrap_or(DEFAULT_INDICATORS))
+        .map_err(|err| (StatusCode::BAD_REQUEST, err))?;
+    if specs.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Aucun indicateur demandé".to_string()));
+    }
+    if specs.len() > MAX_INDICATOR_SPECS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Trop d'indicateurs : {} (maximum {})", specs.len(), MAX_INDICATOR_SPECS),
+        ));
+    }
+
+    let StoredHistory { dto, interval, points } = load_points(&stock_manager, &symbol, query.interval.as_ref()).await?;
+
+    // Calcul sur tout l'historique : hors de l'exécuteur asynchrone
+    let chart = tokio::task::spawn_blocking(move || IndicatorChart::build(&points, &specs))
+        .await
+        .map_err(|err| {
+            eprintln!("Calcul des indicateurs interrompu : {:?}", err);
+            (StatusCode::INTERNAL_SERVER_ERROR, "Erreur lors du calcul des indicateurs".to_string())
+        })?;
+
+    Ok(Json(IndicatorResponse {
+        symbol: dto.symbol.clone(),
+        interval,
+        chart,
+    }))
+}
@@ -0,0 +1,115 @@
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    response::Json,
+    routing::get,
+    Router,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use crate::application::backtest_service::{BacktestService, BacktestSettings, Scorecard, WindowMode};
+use crate::application::predicators::prediction_config::DEFAULT_CONFIDENCE;
+use crate::application::prediction_service::PredictionService;
+use crate::application::stock_manager::StockManager;
+use crate::domain::prediction_point::PredictionPoint;
+use crate::interfaces::stock_handler::load_stock;
+
+// --- QUERY STRUCTS ---
+#[derive(Deserialize)]
+pub struct BacktestQuery {
+    /// Méthodes séparées par des virgules (toutes si absent)
+    methods: Option<String>,
+    window: Option<WindowMode>,
+    train_size: Option<usize>,
+    horizon: Option<usize>,
+    stride: Option<usize>,
+    confidence: Option<f64>,
+}
+
+#[derive(Serialize)]
+pub struct BacktestResponse {
+    symbol: String,
+    points: usize,
+    window: WindowMode,
+    train_size: usize,
+    horizon: usize,
+    /// Écart effectif entre origines (élargi au-delà de `MAX_FOLDS` origines)
+    stride: usize,
+    scorecards: Vec<Scorecard>,
+}
+
+// ---- ROUTER ----
+pub fn backtest_router(stock_manager: Arc<StockManager>, backtest_service: Arc<BacktestService>) -> Router {
+    Router::new()
+        .route("/stocks/:symbol/backtest", get(backtest_stock))
+        .layer(Extension(stock_manager))
+        .layer(Extension(backtest_service))
+}
+
+// ---- HANDLER ----
+async fn backtest_stock(
+    Path(symbol): Path<String>,
+    Query(query): Query<BacktestQuery>,
+    Extension(stock_manager): Extension<Arc<StockManager>>,
+    Extension(backtest_service): Extension<Arc<BacktestService>>,
+    Extension(prediction_service): Extension<Arc<PredictionService>>,
+) -> Result<Json<BacktestResponse>, (StatusCode, String)> {
+    let methods: Vec<String> = query
+        .methods
+        .as_deref()
+        .unwrap_or("")
+        .split(',')
+        .map(|m| m.trim().to_string())
+        .filter(|m| !m.is_empty())
+        .collect();
+
+    if let Some(unknown) = methods.iter().find(|m| prediction_service.find(m).is_none()) {
+        return Err((StatusCode::BAD_REQUEST, format!("Méthode inconnue : {}", unknown)));
+    }
+
+    let confidence = query.confidence.unwrap_or(DEFAULT_CONFIDENCE);
+    if !(confidence > 0.0 && confidence < 1.0) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Niveau de confiance invalide : {} (attendu dans ]0, 1[)", confidence),
+        ));
+    }
+
+    let dto = load_stock(&stock_manager, &symbol).await?;
+
+    let history: Vec<PredictionPoint> = dto.sorted_points().iter().map(PredictionPoint::from).collect();
+
+    let settings = BacktestSettings {
+        window: query.window.unwrap_or(WindowMode::Expanding),
+        train_size: query.train_size.unwrap_or(20).max(2),
+        horizon: query.horizon.unwrap_or(5).max(1),
+        stride: query.stride.unwrap_or(1).max(1),
+        confidence,
+        interval: dto.interval(),
+        parameters: BTreeMap::new(),
+    };
+
+    if history.len() <= settings.train_size {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Historique trop court : {} points pour une fenêtre d'apprentissage de {}",
+                history.len(),
+                settings.train_size
+            ),
+        ));
+    }
+
+    let scorecards = backtest_service.run(&history, &methods, &settings).await;
+
+    Ok(Json(BacktestResponse {
+        symbol: dto.symbol.clone(),
+        points: history.len(),
+        window: settings.window,
+        train_size: settings.train_size,
+        horizon: settings.horizon,
+        stride: settings.effective_stride(history.len()),
+        scorecards,
+    }))
+}
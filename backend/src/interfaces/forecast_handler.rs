@@ -0,0 +1,91 @@
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    response::Json,
+    routing::{get, post},
+    Router,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use crate::application::forecast_tracking_service::{EvaluationReport, ForecastTrackingService};
+use crate::domain::forecast_record::{ForecastRecord, Leaderboard};
+
+// --- REQUEST STRUCTS ---
+#[derive(Deserialize)]
+pub struct LeaderboardQuery {
+    symbol: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ForecastsQuery {
+    method: Option<String>,
+    /// Taille de la page (DEFAULT_FORECAST_PAGE par défaut, MAX_FORECAST_PAGE au plus)
+    limit: Option<usize>,
+    /// Prévisions à sauter, des plus récentes aux plus anciennes
+    offset: Option<usize>,
+}
+
+/// Prévisions renvoyées par page lorsque la requête n'en précise pas le nombre
+const DEFAULT_FORECAST_PAGE: usize = 50;
+/// Taille maximale d'une page de prévisions
+const MAX_FORECAST_PAGE: usize = 500;
+
+// ---- ROUTER ----
+pub fn forecast_router() -> Router {
+    Router::new()
+        .route("/forecasts/leaderboard", get(get_leaderboard))
+        .route("/stocks/:symbol/forecasts", get(get_forecasts))
+        .route("/admin/evaluate-forecasts", post(evaluate_forecasts))
+}
+
+fn internal_error(context: &str, err: anyhow::Error) -> (StatusCode, String) {
+    eprintln!("Erreur lors {} : {:?}", context, err);
+    (StatusCode::INTERNAL_SERVER_ERROR, format!("Erreur lors {}", context))
+}
+
+// ---- HANDLERS ----
+/// Précision réelle des méthodes sur les prévisions archivées
+async fn get_leaderboard(
+    Query(query): Query<LeaderboardQuery>,
+    Extension(tracking_service): Extension<Arc<ForecastTrackingService>>,
+) -> Result<Json<Leaderboard>, (StatusCode, String)> {
+    tracking_service
+        .leaderboard(query.symbol.as_deref())
+        .await
+        .map(Json)
+        .map_err(|err| internal_error("du calcul du classement", err))
+}
+
+/// Prévisions archivées du symbole avec leurs cours réalisés, les plus récentes d'abord, par page
+async fn get_forecasts(
+    Path(symbol): Path<String>,
+    Query(query): Query<ForecastsQuery>,
+    Extension(tracking_service): Extension<Arc<ForecastTrackingService>>,
+) -> Result<Json<Vec<ForecastRecord>>, (StatusCode, String)> {
+    let limit = query.limit.unwrap_or(DEFAULT_FORECAST_PAGE);
+    if limit == 0 || limit > MAX_FORECAST_PAGE {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Taille de page invalide : {} (attendu entre 1 et {})", limit, MAX_FORECAST_PAGE),
+        ));
+    }
+
+    // Les noms de méthode archivés sont en majuscules
+    let method = query.method.map(|m| m.to_ascii_uppercase());
+    tracking_service
+        .forecasts(&symbol, method.as_deref(), query.offset.unwrap_or(0), limit)
+        .await
+        .map(Json)
+        .map_err(|err| internal_error("de la lecture des prévisions", err))
+}
+
+/// Déclenche immédiatement le job d'évaluation des prévisions en attente
+async fn evaluate_forecasts(
+    Extension(tracking_service): Extension<Arc<ForecastTrackingService>>,
+) -> Result<Json<EvaluationReport>, (StatusCode, String)> {
+    tracking_service
+        .evaluate_pending()
+        .await
+        .map(Json)
+        .map_err(|err| internal_error("de l'évaluation des prévisions", err))
+}
@@ -0,0 +1,84 @@
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    response::Json,
+    routing::get,
+    Router,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use crate::application::insight_service::InsightService;
+use crate::domain::insight_profile::{InsightIndicator, InsightProfile};
+
+// --- REQUEST STRUCTS ---
+#[derive(Deserialize)]
+pub struct ProfileQuery {
+    /// Utilisateur propriétaire ; absent pour les profils partagés
+    user: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ProfileRequest {
+    description: Option<String>,
+    indicators: Vec<InsightIndicator>,
+}
+
+// ---- ROUTER ----
+pub fn insight_router() -> Router {
+    Router::new()
+        .route("/insight-profiles", get(list_profiles))
+        .route("/insight-profiles/:name", get(get_profile).put(save_profile))
+}
+
+fn internal_error(context: &str, err: anyhow::Error) -> (StatusCode, String) {
+    eprintln!("Erreur lors {} : {:?}", context, err);
+    (StatusCode::INTERNAL_SERVER_ERROR, format!("Erreur lors {}", context))
+}
+
+// ---- HANDLERS ----
+/// Profils fournis, partagés et, si `user` est précisé, ceux de l'utilisateur
+async fn list_profiles(
+    Query(query): Query<ProfileQuery>,
+    Extension(insight_service): Extension<Arc<InsightService>>,
+) -> Result<Json<Vec<InsightProfile>>, (StatusCode, String)> {
+    insight_service
+        .profiles(query.user.as_deref())
+        .await
+        .map(Json)
+        .map_err(|err| internal_error("de la lecture des profils", err))
+}
+
+/// Profil effectivement appliqué pour ce nom et cet utilisateur
+async fn get_profile(
+    Path(name): Path<String>,
+    Query(query): Query<ProfileQuery>,
+    Extension(insight_service): Extension<Arc<InsightService>>,
+) -> Result<Json<InsightProfile>, (StatusCode, String)> {
+    match insight_service.resolve(Some(&name), query.user.as_deref()).await {
+        Ok(Some(profile)) => Ok(Json(profile)),
+        Ok(None) => Err((StatusCode::NOT_FOUND, format!("Profil d'insights inconnu : {}", name))),
+        Err(err) => Err(internal_error("de la lecture du profil", err)),
+    }
+}
+
+/// Crée ou remplace un profil, partagé ou propre à `user`
+async fn save_profile(
+    Path(name): Path<String>,
+    Query(query): Query<ProfileQuery>,
+    Extension(insight_service): Extension<Arc<InsightService>>,
+    Json(req): Json<ProfileRequest>,
+) -> Result<Json<InsightProfile>, (StatusCode, String)> {
+    let profile = InsightProfile {
+        name: name.trim().to_string(),
+        owner: query.user.filter(|u| !u.trim().is_empty()),
+        description: req.description,
+        indicators: req.indicators,
+    };
+    profile.validate().map_err(|err| (StatusCode::BAD_REQUEST, err))?;
+
+    insight_service
+        .save(&profile)
+        .await
+        .map_err(|err| internal_error("de l'enregistrement du profil", err))?;
+    Ok(Json(profile))
+}
@@ -1,2 +1,7 @@
 pub mod admin_handler;
-pub mod stock_handler;
\ No newline at end of file
+pub mod analysis_handler;
+pub mod backtest_handler;
+pub mod forecast_handler;
+pub mod insight_handler;
+pub mod stock_handler;
+pub mod tuning_handler;
\ No newline at end of file
@@ -4,14 +4,25 @@ use axum::{
     routing::get,
     Router,
 };
-use crate::application::stock_manager::StockManager;
+use crate::application::stock_manager::{GenericStockDataDTO, StockManager};
 use crate::domain::stock_summary::StockSummary;
 use crate::infrastructure::db::mongo_stock_manager::MongoStockManager;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::sync::Arc;
+use axum::http::StatusCode;
 use axum::routing::post;
-use crate::application::prediction_service::PredictionService;
+use crate::application::predicators::monte_carlo_predictor::{MonteCarloPredictor, Simulation, DEFAULT_SIMULATIONS, MAX_SAMPLE_PATHS, MAX_SIMULATED_STEPS, MAX_SIMULATIONS};
+use crate::application::predicators::prediction_config::{BandModel, ModelTarget, PredictionConfig, ReferenceSeries, DEFAULT_CONFIDENCE, DEFAULT_HORIZON, DEFAULT_RESAMPLES, MAX_HORIZON, MAX_MODEL_STEPS, MAX_RESAMPLES};
+use crate::application::prediction_service::{PredictionError, PredictionService, PredictorInfo};
+use crate::application::tuning_service::TuningService;
+use crate::application::forecast_tracking_service::ForecastTrackingService;
+use crate::application::insight_service::InsightService;
+use crate::domain::insight_profile::DEFAULT_PROFILE;
+use crate::domain::stock_insights::StockInsights;
+use crate::domain::forecast::Forecast;
 use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::time_series::{self, StockPoint, TimeInterval};
 
 // --- QUERY STRUCTS ---
 #[derive(Deserialize)]
@@ -22,17 +33,150 @@ pub struct SearchQuery {
 #[derive(Deserialize)]
 pub struct PredictQuery {
     method: String,
+    horizon: Option<usize>,
+    step: Option<u32>,
+    confidence: Option<f64>,
+    /// Granularité de travail ; l'historique stocké est agrégé si elle est plus grossière
+    interval: Option<TimeInterval>,
+    /// Nombre de points les plus récents utilisés pour l'ajustement
+    lookback: Option<usize>,
+    bands: Option<BandModel>,
+    target: Option<ModelTarget>,
+    resamples: Option<usize>,
+    /// Hyperparamètres de la méthode, sous la forme "window:30,alpha:0.2"
+    params: Option<String>,
+    /// Autres symboles stockés, séparés par des virgules, servant de séries de référence
+    references: Option<String>,
 }
 
 #[derive(Deserialize)]
 pub struct PredictRequest {
     pub method: String,
+    /// Symbole de l'historique : ses paramètres réglés servent de valeurs par défaut
+    #[serde(default)]
+    pub symbol: Option<String>,
     pub history: Vec<PredictionPoint>,
+    #[serde(flatten)]
+    pub options: ForecastOptions,
+    /// Hyperparamètres de la méthode (cf. catalogue `/predictors`)
+    #[serde(default)]
+    pub parameters: BTreeMap<String, serde_json::Value>,
+    /// Autres symboles stockés servant de séries de référence (analogues…)
+    #[serde(default)]
+    pub references: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub struct SimulateRequest {
+    pub history: Vec<PredictionPoint>,
+    #[serde(flatten)]
+    pub options: ForecastOptions,
+    /// Nombre de trajectoires simulées
+    #[serde(default = "default_simulations")]
+    pub simulations: usize,
+    /// Nombre de trajectoires brutes renvoyées pour le fan chart
+    #[serde(default)]
+    pub sample_paths: usize,
+}
+
+/// Options de prévision communes aux requêtes de prédiction
+#[derive(Deserialize)]
+pub struct ForecastOptions {
+    /// Niveau de confiance des bandes upper/lower (0.95 par défaut)
+    #[serde(default = "default_confidence")]
+    pub confidence: f64,
+    /// Nombre de points à prédire
+    #[serde(default = "default_horizon")]
+    pub horizon: usize,
+    /// Nombre d'intervalles entre deux points prédits
+    #[serde(default = "default_step")]
+    pub step: u32,
+    /// Granularité de l'historique envoyé (Day par défaut)
+    #[serde(default = "default_interval")]
+    pub interval: TimeInterval,
+    /// "model" (bandes propres au prédicteur, défaut), "garch" ou "bootstrap"
+    #[serde(default)]
+    pub bands: BandModel,
+    /// Nombre de rééchantillonnages des bandes "bootstrap"
+    #[serde(default = "default_resamples")]
+    pub resamples: usize,
+    /// "price" (cours bruts, défaut) ou "returns" (rendements logarithmiques recomposés en prix)
+    #[serde(default)]
+    pub target: ModelTarget,
+}
+
+fn default_confidence() -> f64 {
+    DEFAULT_CONFIDENCE
+}
+
+fn default_horizon() -> usize {
+    DEFAULT_HORIZON
+}
+
+fn default_step() -> u32 {
+    1
+}
+
+fn default_interval() -> TimeInterval {
+    TimeInterval::Day
+}
+
+fn default_resamples() -> usize {
+    DEFAULT_RESAMPLES
+}
+
+fn default_simulations() -> usize {
+    DEFAULT_SIMULATIONS
+}
+
+impl ForecastOptions {
+    fn to_config(&self) -> Result<PredictionConfig, (StatusCode, String)> {
+        if !(self.confidence > 0.0 && self.confidence < 1.0) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("Niveau de confiance invalide : {} (attendu dans ]0, 1[)", self.confidence),
+            ));
+        }
+        if self.horizon == 0 || self.horizon > MAX_HORIZON {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("Horizon invalide : {} (attendu entre 1 et {})", self.horizon, MAX_HORIZON),
+            ));
+        }
+        if self.step == 0 {
+            return Err((StatusCode::BAD_REQUEST, "Le pas doit être au moins 1".to_string()));
+        }
+        if self.horizon.saturating_mul(self.step as usize) > MAX_MODEL_STEPS {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "Horizon × pas trop grand : {} × {} (au plus {} pas unitaires)",
+                    self.horizon, self.step, MAX_MODEL_STEPS
+                ),
+            ));
+        }
+        if self.resamples == 0 || self.resamples > MAX_RESAMPLES {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("Nombre de rééchantillonnages invalide : {} (attendu entre 1 et {})", self.resamples, MAX_RESAMPLES),
+            ));
+        }
+
+        let mut config = PredictionConfig::new(self.confidence, self.horizon, self.step, self.interval.clone());
+        config.bands = self.bands;
+        config.target = self.target;
+        config.resamples = self.resamples;
+        Ok(config)
+    }
 }
 
 #[derive(Deserialize)]
 pub struct StockQuery {
     symbol: String,
+    /// Profil d'insights ("default" si absent)
+    profile: Option<String>,
+    /// Utilisateur dont les profils enregistrés sont prioritaires
+    user: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -43,30 +187,6 @@ pub struct StockSummaryResponse {
 }
 
 
-#[derive(Serialize)]
-pub struct StockInsightsResponse {
-    last_price: Option<f64>,
-    day_change: Option<f64>,
-    day_change_percent: Option<f64>,
-    sma_7: Option<f64>,
-    sma_30: Option<f64>,
-    ema_7: Option<f64>,
-    ema_30: Option<f64>,
-    bollinger_upper: Option<f64>,
-    bollinger_lower: Option<f64>,
-    rsi_14: Option<f64>,
-    macd: Option<f64>,
-    atr_14: Option<f64>,
-    max_drawdown_30d: Option<f64>,
-    trend: Option<String>,
-    cumulative_gain_30d: Option<f64>,
-    volume_avg_30d: Option<f64>,
-    volatility_30d: Option<f64>,
-    price_vs_sector: Option<f64>,
-    alert_overbought: Option<bool>,
-    alert_oversold: Option<bool>,
-}
-
 #[derive(Serialize)]
 pub struct StockPointResponse {
     timestamp: chrono::DateTime<chrono::Utc>,
@@ -82,7 +202,7 @@ pub struct StockResponse {
     symbol: String,
     provider: Option<String>,
     historical_segments: Vec<StockSegmentResponse>,
-    insights: StockInsightsResponse,
+    insights: StockInsights,
 }
 
 #[derive(Serialize)]
@@ -101,11 +221,133 @@ pub fn create_router(
     Router::new()
         .route("/stocks/search", get(search_stock))
         .route("/stocks/info", get(get_stock_info))
+        .route("/predictors", get(list_predictors))
         .route("/stock/predict", post(predict_stock))
+        .route("/stock/simulate", post(simulate_stock))
+        .route("/stocks/:symbol/predict", get(predict_stored_stock))
         .layer(Extension(stock_manager))
         .layer(Extension(mongo_manager))
 }
 
+// ---- HELPERS ----
+/// Charge un symbole via le StockManager, en traduisant l'absence et les erreurs en réponses HTTP
+pub async fn load_stock(stock_manager: &StockManager, symbol: &str) -> Result<GenericStockDataDTO, (StatusCode, String)> {
+    match stock_manager.get_stock_dto(symbol).await {
+        Ok(Some(dto)) => Ok(dto),
+        Ok(None) => Err((StatusCode::NOT_FOUND, format!("Symbole introuvable : {}", symbol))),
+        Err(err) => {
+            eprintln!("Erreur lors de la récupération du stock : {:?}", err);
+            Err((StatusCode::INTERNAL_SERVER_ERROR, "Erreur lors de la récupération du stock".to_string()))
+        }
+    }
+}
+
+/// Nombre maximal de séries de référence par requête
+const MAX_REFERENCES: usize = 10;
+
+/// Historique stocké d'un symbole, à la granularité retenue
+pub(crate) struct StoredHistory {
+    pub dto: GenericStockDataDTO,
+    pub interval: TimeInterval,
+    pub points: Vec<StockPoint>,
+}
+
+/// Historique stocké d'un symbole à la granularité demandée (celle des données si absente),
+/// agrégé si elle est plus grossière ; 400 si elle est plus fine que les données
+pub(crate) async fn load_points(
+    stock_manager: &StockManager,
+    symbol: &str,
+    interval: Option<&TimeInterval>,
+) -> Result<StoredHistory, (StatusCode, String)> {
+    let dto = load_stock(stock_manager, symbol).await?;
+    let interval = interval.cloned().unwrap_or_else(|| dto.interval());
+    stored_history(dto, symbol, interval)
+}
+
+/// Historique stocké d'un symbole, agrégé à `finest` si les données sont plus fines
+pub(crate) async fn load_points_at_least(
+    stock_manager: &StockManager,
+    symbol: &str,
+    finest: &TimeInterval,
+) -> Result<StoredHistory, (StatusCode, String)> {
+    let dto = load_stock(stock_manager, symbol).await?;
+    let stored_interval = dto.interval();
+    let interval = if stored_interval.rank() < finest.rank() { finest.clone() } else { stored_interval };
+    stored_history(dto, symbol, interval)
+}
+
+/// Points triés de `dto` à la granularité `interval` ; 400 si elle est plus fine que les données
+fn stored_history(dto: GenericStockDataDTO, symbol: &str, interval: TimeInterval) -> Result<StoredHistory, (StatusCode, String)> {
+    let stored_interval = dto.interval();
+    if interval.rank() < stored_interval.rank() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Intervalle {:?} plus fin que les données stockées de {} ({:?})", interval, symbol, stored_interval),
+        ));
+    }
+
+    let mut points = dto.sorted_points();
+    if interval.rank() > stored_interval.rank() {
+        points = time_series::resample(&points, &interval);
+    }
+    Ok(StoredHistory { dto, interval, points })
+}
+
+/// Charge les séries de référence demandées
+async fn load_references(
+    stock_manager: &StockManager,
+    symbols: &[String],
+    interval: &TimeInterval,
+) -> Result<Vec<ReferenceSeries>, (StatusCode, String)> {
+    if symbols.len() > MAX_REFERENCES {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("{} séries de référence demandées (au plus {})", symbols.len(), MAX_REFERENCES),
+        ));
+    }
+
+    let mut references = Vec::with_capacity(symbols.len());
+    for symbol in symbols {
+        let stored = load_points(stock_manager, symbol, Some(interval)).await?;
+        references.push(ReferenceSeries {
+            symbol: stored.dto.symbol.clone(),
+            points: stored.points.iter().map(PredictionPoint::from).collect(),
+        });
+    }
+    Ok(references)
+}
+
+/// Hyperparamètres JSON : seules les valeurs numériques sont acceptées
+fn parse_parameters(raw: &BTreeMap<String, serde_json::Value>) -> Result<BTreeMap<String, f64>, (StatusCode, String)> {
+    raw.iter()
+        .map(|(name, value)| {
+            value.as_f64().map(|v| (name.clone(), v)).ok_or_else(|| {
+                (StatusCode::BAD_REQUEST, format!("Paramètre '{}' invalide : nombre attendu, reçu {}", name, value))
+            })
+        })
+        .collect()
+}
+
+/// Hyperparamètres passés en query string : "nom:valeur" séparés par des virgules
+fn parse_parameter_list(raw: &str) -> Result<BTreeMap<String, f64>, (StatusCode, String)> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let invalid = || (StatusCode::BAD_REQUEST, format!("Paramètre invalide : '{}' (attendu nom:valeur)", entry));
+            let (name, value) = entry.split_once(':').ok_or_else(invalid)?;
+            let value: f64 = value.trim().parse().map_err(|_| invalid())?;
+            Ok((name.trim().to_string(), value))
+        })
+        .collect()
+}
+
+/// Traduit une erreur de prédiction en réponse HTTP
+fn prediction_error(err: PredictionError) -> (StatusCode, String) {
+    eprintln!("Prédiction impossible : {}", err);
+    (StatusCode::BAD_REQUEST, err.to_string())
+}
+
 // ---- HANDLERS ----
 async fn search_stock(
     Query(query): Query<SearchQuery>,
@@ -132,7 +374,22 @@ async fn search_stock(
 async fn get_stock_info(
     Query(query): Query<StockQuery>,
     Extension(stock_manager): Extension<Arc<StockManager>>,
-) -> Json<Option<StockResponse>> {
+    Extension(insight_service): Extension<Arc<InsightService>>,
+) -> Result<Json<Option<StockResponse>>, (StatusCode, String)> {
+    let profile = match insight_service.resolve(query.profile.as_deref(), query.user.as_deref()).await {
+        Ok(Some(profile)) => profile,
+        Ok(None) => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                format!("Profil d'insights inconnu : {}", query.profile.as_deref().unwrap_or(DEFAULT_PROFILE)),
+            ))
+        }
+        Err(err) => {
+            eprintln!("Erreur lors de la lecture du profil d'insights : {:?}", err);
+            return Err((StatusCode::INTERNAL_SERVER_ERROR, "Erreur lors de la lecture du profil d'insights".to_string()));
+        }
+    };
+
     match stock_manager.get_stock_dto(&query.symbol).await {
         Ok(Some(dto)) => {
             let historical_segments: Vec<StockSegmentResponse> = dto
@@ -157,54 +414,147 @@ async fn get_stock_info(
                 })
                 .collect();
 
-            let insights_ref = dto.insights(); // maintenant sûr, pas de move conflict
-
-            let insights_response = StockInsightsResponse {
-                last_price: insights_ref.last_price,
-                day_change: insights_ref.day_change,
-                day_change_percent: insights_ref.day_change_percent,
-                sma_7: insights_ref.sma_7,
-                sma_30: insights_ref.sma_30,
-                ema_7: insights_ref.ema_7,
-                ema_30: insights_ref.ema_30,
-                bollinger_upper: insights_ref.bollinger_upper,
-                bollinger_lower: insights_ref.bollinger_lower,
-                rsi_14: insights_ref.rsi_14,
-                macd: insights_ref.macd,
-                atr_14: insights_ref.atr_14,
-                max_drawdown_30d: insights_ref.max_drawdown_30d,
-                trend: insights_ref.trend.clone(),
-                cumulative_gain_30d: insights_ref.cumulative_gain_30d,
-                volume_avg_30d: insights_ref.volume_avg_30d,
-                volatility_30d: insights_ref.volatility_30d,
-                price_vs_sector: insights_ref.price_vs_sector,
-                alert_overbought: insights_ref.alert_overbought,
-                alert_oversold: insights_ref.alert_oversold,
-            };
-
-            Json(Some(StockResponse {
+            let insights = insight_service.insights(&dto, &profile);
+
+            Ok(Json(Some(StockResponse {
                 symbol: dto.symbol.clone(),
                 provider: dto.provider.clone(),
                 historical_segments,
-                insights: insights_response,
-            }))
+                insights,
+            })))
         }
-        Ok(None) => Json(None),
+        Ok(None) => Ok(Json(None)),
         Err(err) => {
             eprintln!("Erreur lors de la récupération du stock : {:?}", err);
-            Json(None)
+            Ok(Json(None))
         }
     }
 }
 
+/// Catalogue des méthodes de prédiction disponibles
+async fn list_predictors(
+    Extension(prediction_service): Extension<Arc<PredictionService>>,
+) -> Json<Vec<PredictorInfo>> {
+    Json(prediction_service.catalogue())
+}
+
 #[axum::debug_handler]
 pub async fn predict_stock(
+    Extension(stock_manager): Extension<Arc<StockManager>>,
     Extension(prediction_service): Extension<Arc<PredictionService>>,
+    Extension(tuning_service): Extension<Arc<TuningService>>,
     axum::Json(req): axum::Json<PredictRequest>,
-) -> axum::Json<Vec<PredictionPoint>> {
-    let predictions = prediction_service
-        .predict_from_history(&req.method, &req.history)
-        .await;
+) -> Result<axum::Json<Forecast>, (StatusCode, String)> {
+    let mut config = req.options.to_config()?;
+    let overrides = parse_parameters(&req.parameters)?;
+    if let Some(symbol) = &req.symbol {
+        config.parameters = tuning_service.tuned_parameters(symbol, &req.method).await;
+    }
+    config.parameters.extend(overrides);
+    config.references = load_references(&stock_manager, &req.references, &config.interval).await?;
+    let forecast = prediction_service
+        .predict_from_history(&req.method, &req.history, &config)
+        .await
+        .map_err(prediction_error)?;
+
+    Ok(axum::Json(forecast))
+}
+
+/// Prédiction à partir de l'historique stocké du symbole, sans aller-retour du client
+pub async fn predict_stored_stock(
+    Path(symbol): Path<String>,
+    Query(query): Query<PredictQuery>,
+    Extension(stock_manager): Extension<Arc<StockManager>>,
+    Extension(prediction_service): Extension<Arc<PredictionService>>,
+    Extension(tuning_service): Extension<Arc<TuningService>>,
+    Extension(tracking_service): Extension<Arc<ForecastTrackingService>>,
+) -> Result<axum::Json<Forecast>, (StatusCode, String)> {
+    let StoredHistory { dto, interval, mut points } = load_points(&stock_manager, &symbol, query.interval.as_ref()).await?;
+
+    let options = ForecastOptions {
+        confidence: query.confidence.unwrap_or(DEFAULT_CONFIDENCE),
+        horizon: query.horizon.unwrap_or(DEFAULT_HORIZON),
+        step: query.step.unwrap_or(1),
+        interval: interval.clone(),
+        bands: query.bands.unwrap_or_default(),
+        target: query.target.unwrap_or_default(),
+        resamples: query.resamples.unwrap_or(DEFAULT_RESAMPLES),
+    };
+    let mut config = options.to_config()?;
+    let overrides = parse_parameter_list(query.params.as_deref().unwrap_or(""))?;
+    config.parameters = tuning_service.tuned_parameters(&dto.symbol, &query.method).await;
+    config.parameters.extend(overrides);
+    let references: Vec<String> = query
+        .references
+        .as_deref()
+        .unwrap_or("")
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+    config.references = load_references(&stock_manager, &references, &interval).await?;
+
+    if let Some(lookback) = query.lookback {
+        if lookback == 0 {
+            return Err((StatusCode::BAD_REQUEST, "La fenêtre d'historique doit être au moins 1".to_string()));
+        }
+        points = points.split_off(points.len().saturating_sub(lookback));
+    }
+
+    let history: Vec<PredictionPoint> = points.iter().map(PredictionPoint::from).collect();
+    let forecast = prediction_service
+        .predict_from_history(&query.method, &history, &config)
+        .await
+        .map_err(prediction_error)?;
+    tracking_service.record(&dto.symbol, &forecast, &history, &config).await;
 
-    axum::Json(predictions)
+    Ok(axum::Json(forecast))
+}
+
+/// Simulation de Monte Carlo avec, en option, un échantillon de trajectoires brutes
+pub async fn simulate_stock(
+    axum::Json(req): axum::Json<SimulateRequest>,
+) -> Result<axum::Json<Simulation>, (StatusCode, String)> {
+    let config = req.options.to_config()?;
+    if req.simulations == 0 || req.simulations > MAX_SIMULATIONS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Nombre de simulations invalide : {} (attendu entre 1 et {})", req.simulations, MAX_SIMULATIONS),
+        ));
+    }
+
+    if req.sample_paths > MAX_SAMPLE_PATHS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Trop de trajectoires demandées : {} (au plus {})", req.sample_paths, MAX_SAMPLE_PATHS),
+        ));
+    }
+    let simulated_steps = req.simulations.saturating_mul(config.model_steps());
+    if simulated_steps > MAX_SIMULATED_STEPS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Simulation trop coûteuse : {} trajectoires × {} pas dépassent {} pas simulés",
+                req.simulations,
+                config.model_steps(),
+                MAX_SIMULATED_STEPS
+            ),
+        ));
+    }
+
+    // Calcul intensif : sur les threads bloquants plutôt que sur l'exécuteur asynchrone
+    let SimulateRequest { history, simulations, sample_paths, .. } = req;
+    let simulation = tokio::task::spawn_blocking(move || MonteCarloPredictor::new(simulations).simulate(&history, &config, sample_paths))
+        .await
+        .map_err(|err| {
+            eprintln!("Simulation interrompue : {:?}", err);
+            (StatusCode::INTERNAL_SERVER_ERROR, "Erreur lors de la simulation".to_string())
+        })?;
+    match simulation {
+        Some(simulation) => Ok(axum::Json(simulation)),
+        None => Err((
+            StatusCode::BAD_REQUEST,
+            "Historique insuffisant pour estimer la dérive et la volatilité".to_string(),
+        )),
+    }
 }
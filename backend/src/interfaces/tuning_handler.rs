@@ -0,0 +1,159 @@
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    response::Json,
+    routing::{get, post},
+    Router,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use crate::application::backtest_service::{BacktestSettings, WindowMode};
+use crate::application::predicators::prediction_config::DEFAULT_CONFIDENCE;
+use crate::application::prediction_service::PredictionService;
+use crate::application::stock_manager::StockManager;
+use crate::application::tuning_service::{TuningService, TuningSettings, MAX_TRIALS, MAX_TUNING_COST};
+use crate::domain::prediction_point::PredictionPoint;
+use crate::domain::tuning::{SearchStrategy, TuningResult};
+use crate::interfaces::stock_handler::load_stock;
+
+// --- REQUEST STRUCTS ---
+#[derive(Deserialize)]
+pub struct TuneRequest {
+    method: String,
+    strategy: Option<SearchStrategy>,
+    /// Valeurs testées par paramètre en recherche sur grille (5 par défaut)
+    grid_points: Option<usize>,
+    /// Nombre de tirages en recherche aléatoire (20 par défaut)
+    trials: Option<usize>,
+    window: Option<WindowMode>,
+    train_size: Option<usize>,
+    horizon: Option<usize>,
+    stride: Option<usize>,
+}
+
+#[derive(Deserialize)]
+pub struct TuningQuery {
+    method: Option<String>,
+}
+
+// ---- ROUTER ----
+pub fn tuning_router(stock_manager: Arc<StockManager>) -> Router {
+    Router::new()
+        .route("/stocks/:symbol/tune", post(tune_stock))
+        .route("/stocks/:symbol/tuning", get(get_tuning))
+        .layer(Extension(stock_manager))
+}
+
+// ---- HANDLERS ----
+/// Lance une recherche d'hyperparamètres et enregistre le meilleur réglage du symbole
+async fn tune_stock(
+    Path(symbol): Path<String>,
+    Extension(stock_manager): Extension<Arc<StockManager>>,
+    Extension(prediction_service): Extension<Arc<PredictionService>>,
+    Extension(tuning_service): Extension<Arc<TuningService>>,
+    Json(req): Json<TuneRequest>,
+) -> Result<Json<TuningResult>, (StatusCode, String)> {
+    let predictor = prediction_service
+        .find(&req.method)
+        .ok_or_else(|| (StatusCode::BAD_REQUEST, format!("Méthode inconnue : {}", req.method)))?;
+    let specs = predictor.parameters();
+    if specs.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("La méthode {} n'a aucun paramètre réglable", predictor.method_name()),
+        ));
+    }
+
+    let grid_points = req.grid_points.unwrap_or(5);
+    if !(2..=MAX_TRIALS).contains(&grid_points) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Nombre de valeurs par paramètre invalide : {} (attendu entre 2 et {})", grid_points, MAX_TRIALS),
+        ));
+    }
+    let trials = req.trials.unwrap_or(20);
+    if !(1..=MAX_TRIALS).contains(&trials) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Nombre de tirages invalide : {} (attendu entre 1 et {})", trials, MAX_TRIALS),
+        ));
+    }
+
+    let dto = load_stock(&stock_manager, &symbol).await?;
+    let history: Vec<PredictionPoint> = dto.sorted_points().iter().map(PredictionPoint::from).collect();
+
+    let settings = TuningSettings {
+        strategy: req.strategy.unwrap_or(SearchStrategy::Grid),
+        grid_points,
+        trials,
+        backtest: BacktestSettings {
+            window: req.window.unwrap_or(WindowMode::Expanding),
+            train_size: req.train_size.unwrap_or(20).max(2),
+            horizon: req.horizon.unwrap_or(5).max(1),
+            stride: req.stride.unwrap_or(1).max(1),
+            confidence: DEFAULT_CONFIDENCE,
+            interval: dto.interval(),
+            parameters: BTreeMap::new(),
+        },
+    };
+
+    if history.len() <= settings.backtest.train_size {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Historique trop court : {} points pour une fenêtre d'apprentissage de {}",
+                history.len(),
+                settings.backtest.train_size
+            ),
+        ));
+    }
+
+    // Bornes vérifiées avant toute construction des combinaisons
+    let count = TuningService::candidate_count(&specs, &settings);
+    if count == 0 || count > MAX_TRIALS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("{} combinaisons demandées (attendu entre 1 et {})", count, MAX_TRIALS),
+        ));
+    }
+    let candidates = TuningService::candidates(&specs, &settings);
+    let cost = TuningService::search_cost(predictor.as_ref(), history.len(), &candidates, &settings);
+    if cost > MAX_TUNING_COST {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Recherche trop coûteuse : {} ajustements équivalents pour {} au plus (réduire les combinaisons, l'historique ou élargir le pas)",
+                cost, MAX_TUNING_COST
+            ),
+        ));
+    }
+
+    match tuning_service.tune(&dto.symbol, predictor.clone(), history, candidates, &settings).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            eprintln!("Erreur lors de l'enregistrement du réglage : {:?}", err);
+            Err((StatusCode::INTERNAL_SERVER_ERROR, "Erreur lors de l'enregistrement du réglage".to_string()))
+        }
+    }
+}
+
+/// Tableaux de résultats des recherches enregistrées pour le symbole
+async fn get_tuning(
+    Path(symbol): Path<String>,
+    Query(query): Query<TuningQuery>,
+    Extension(tuning_service): Extension<Arc<TuningService>>,
+) -> Result<Json<Vec<TuningResult>>, (StatusCode, String)> {
+    match tuning_service.results(&symbol).await {
+        Ok(results) => Ok(Json(
+            results
+                .into_iter()
+                .filter(|r| query.method.as_ref().is_none_or(|m| r.method.eq_ignore_ascii_case(m)))
+                .collect(),
+        )),
+        Err(err) => {
+            eprintln!("Erreur lors de la lecture des réglages : {:?}", err);
+            Err((StatusCode::INTERNAL_SERVER_ERROR, "Erreur lors de la lecture des réglages".to_string()))
+        }
+    }
+}
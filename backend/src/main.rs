@@ -20,11 +20,28 @@ use interfaces::stock_handler::create_router;
 use application::stock_manager::StockManager;
 use std::env;
 use interfaces::admin_handler;
+use interfaces::analysis_handler;
+use interfaces::backtest_handler;
+use interfaces::tuning_handler;
+use interfaces::forecast_handler;
+use interfaces::insight_handler;
 use crate::application::predicators::{NaivePredictor, SmaPredictor, StockPredictor};
 use crate::application::predicators::ar_predictor::ArPredictor;
+use crate::application::predicators::arima_predictor::ArimaPredictor;
+use crate::application::predicators::holt_winters_predictor::{HoltPredictor, HoltWintersPredictor};
+use crate::application::predicators::kalman_predictor::KalmanPredictor;
+use crate::application::predicators::analog_predictor::{AnalogPredictor, Distance};
+use crate::application::predicators::return_predictors::{BootstrapPredictor, DriftPredictor};
+use crate::application::predicators::monte_carlo_predictor::MonteCarloPredictor;
+use crate::application::predicators::ensemble_predictor::EnsemblePredictor;
 use crate::application::predicators::ema_predictor::EmaPredictor;
 use crate::application::predicators::linear_regression_predictor::LinearRegressionPredictor;
 use crate::application::prediction_service::PredictionService;
+use crate::application::backtest_service::BacktestService;
+use crate::application::tuning_service::TuningService;
+use crate::application::forecast_tracking_service::ForecastTrackingService;
+use crate::application::insight_service::InsightService;
+use std::time::Duration;
 
 #[tokio::main]
 async fn main() {
@@ -56,25 +73,55 @@ async fn main() {
     let external_repos: Vec<Arc<dyn StockRepository>> = vec![finnhub_repo, fake_repo];
 
     let stock_manager = Arc::new(StockManager::new(mongo_manager.clone(), external_repos));
-    let predictors: Vec<Arc<dyn StockPredictor>> = vec![
+    let mut predictors: Vec<Arc<dyn StockPredictor>> = vec![
         Arc::new(NaivePredictor),
         Arc::new(SmaPredictor),
         Arc::new(EmaPredictor),
         Arc::new(LinearRegressionPredictor),
         Arc::new(ArPredictor),
+        Arc::new(ArimaPredictor),
+        Arc::new(HoltPredictor),
+        Arc::new(HoltWintersPredictor),
+        Arc::new(KalmanPredictor),
+        Arc::new(AnalogPredictor::new(Distance::Euclidean)),
+        Arc::new(AnalogPredictor::new(Distance::Dtw)),
+        Arc::new(MonteCarloPredictor::default()),
+        Arc::new(DriftPredictor),
+        Arc::new(BootstrapPredictor),
     ];
+    predictors.push(Arc::new(EnsemblePredictor::new(predictors.clone())));
 
     let prediction_service = Arc::new(PredictionService::new(predictors));
+    let backtest_service = Arc::new(BacktestService::new(prediction_service.clone()));
+    let tuning_service = Arc::new(TuningService::new(prediction_service.clone(), mongo_manager.clone()));
+    let tracking_service = Arc::new(ForecastTrackingService::new(mongo_manager.clone(), mongo_manager.clone()));
+    let insight_service = Arc::new(InsightService::new(mongo_manager.clone()));
+
+    // Confrontation périodique des prévisions archivées aux cours réalisés
+    let evaluation_minutes = env::var("FORECAST_EVALUATION_MINUTES")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|m| *m > 0)
+        .unwrap_or(60);
+    tracking_service.clone().spawn_evaluation_job(Duration::from_secs(evaluation_minutes * 60));
 
     let app = Router::new()
         .nest(
             "/api",
             create_router(mongo_manager.clone(), stock_manager.clone())
                 .merge(admin_handler::admin_router(mongo_manager.clone()))
+                .merge(backtest_handler::backtest_router(stock_manager.clone(), backtest_service))
+                .merge(analysis_handler::analysis_router(stock_manager.clone()))
+                .merge(tuning_handler::tuning_router(stock_manager.clone()))
+                .merge(forecast_handler::forecast_router())
+                .merge(insight_handler::insight_router())
         )
         .layer(cors)
         .layer(Extension(mongo_manager.clone()))
-        .layer(Extension(prediction_service));
+        .layer(Extension(prediction_service))
+        .layer(Extension(tuning_service))
+        .layer(Extension(tracking_service))
+        .layer(Extension(insight_service));
 
     let listener = TcpListener::bind("0.0.0.0:3000").await.unwrap();
     println!("Listening on {}", listener.local_addr().unwrap());
@@ -19,27 +19,12 @@ export function mapToGenericStockDataDTO(data: any): GenericStockDataDTO {
                 volume: pt.volume,
             })),
         })),
-        insights: data.insights ? {
-            last_price: data.insights.last_price,
-            day_change: data.insights.day_change,
-            day_change_percent: data.insights.day_change_percent,
-            sma_7: data.insights.sma_7,
-            sma_30: data.insights.sma_30,
-            ema_7: data.insights.ema_7,
-            ema_30: data.insights.ema_30,
-            bollinger_upper: data.insights.bollinger_upper,
-            bollinger_lower: data.insights.bollinger_lower,
-            rsi_14: data.insights.rsi_14,
-            macd: data.insights.macd,
-            atr_14: data.insights.atr_14,
-            max_drawdown_30d: data.insights.max_drawdown_30d,
-            trend: data.insights.trend,
-            cumulative_gain_30d: data.insights.cumulative_gain_30d,
-            volume_avg_30d: data.insights.volume_avg_30d,
-            volatility_30d: data.insights.volatility_30d,
-            price_vs_sector: data.insights.price_vs_sector,
-            alert_overbought: data.insights.alert_overbought,
-            alert_oversold: data.insights.alert_oversold,
-        } as IStockInsights : {} as IStockInsights,
+        insights: {
+            profile: data.insights?.profile ?? "default",
+            last_price: data.insights?.last_price ?? undefined,
+            trend: data.insights?.trend ?? undefined,
+            values: data.insights?.values ?? {},
+            alerts: data.insights?.alerts ?? {},
+        } as IStockInsights,
     };
 }
\ No newline at end of file
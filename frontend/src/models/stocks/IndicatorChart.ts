@@ -0,0 +1,13 @@
+export interface IndicatorSeries {
+    /** Nom canonique, ex. "sma:20" */
+    name: string;
+    /** Une courbe "value", ou plusieurs ("upper", "lower" pour Bollinger) ; null tant que la fenêtre est incomplète */
+    lines: Record<string, (number | null)[]>;
+}
+
+export interface IndicatorChart {
+    symbol: string;
+    interval: string;
+    timestamps: string[];
+    indicators: IndicatorSeries[];
+}
@@ -0,0 +1,18 @@
+export interface ParameterSpec {
+    name: string;
+    kind: "integer" | "float";
+    description: string;
+    default: number | null;
+    min: number;
+    max: number;
+}
+
+export type ModelTarget = "price" | "returns";
+
+export interface PredictorInfo {
+    method: string;
+    description: string;
+    min_history: number;
+    models_returns: boolean;
+    parameters: ParameterSpec[];
+}
\ No newline at end of file
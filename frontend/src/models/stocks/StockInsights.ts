@@ -1,59 +1,24 @@
+/**
+ * Insights calculés selon un profil : `values` est indexé par indicateur
+ * ("rsi:14", ou "bollinger:20.upper" pour un indicateur à plusieurs courbes),
+ * `alerts` par nom d'alerte.
+ */
 export interface IStockInsights {
+    profile: string;
     last_price?: number;
-    day_change?: number;
-    day_change_percent?: number;
-
-    sma_7?: number;
-    sma_30?: number;
-    ema_7?: number;
-    ema_30?: number;
-
-    bollinger_upper?: number;
-    bollinger_lower?: number;
-
-    rsi_14?: number;
-    macd?: number;
-    atr_14?: number;
-
-    max_drawdown_30d?: number;
     trend?: string;
-    cumulative_gain_30d?: number;
-    volume_avg_30d?: number;
-    volatility_30d?: number;
-
-    price_vs_sector?: number;
-    alert_overbought?: boolean;
-    alert_oversold?: boolean;
+    values: Record<string, number | null>;
+    alerts: Record<string, boolean>;
 }
 
 export class StockInsights implements IStockInsights {
+    profile = "default";
     last_price?: number;
-    day_change?: number;
-    day_change_percent?: number;
-
-    sma_7?: number;
-    sma_30?: number;
-    ema_7?: number;
-    ema_30?: number;
-
-    bollinger_upper?: number;
-    bollinger_lower?: number;
-
-    rsi_14?: number;
-    macd?: number;
-    atr_14?: number;
-
-    max_drawdown_30d?: number;
     trend?: string;
-    cumulative_gain_30d?: number;
-    volume_avg_30d?: number;
-    volatility_30d?: number;
-
-    price_vs_sector?: number;
-    alert_overbought?: boolean;
-    alert_oversold?: boolean;
+    values: Record<string, number | null> = {};
+    alerts: Record<string, boolean> = {};
 
     constructor(init?: Partial<IStockInsights>) {
         Object.assign(this, init);
     }
-}
\ No newline at end of file
+}
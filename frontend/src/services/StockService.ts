@@ -3,6 +3,8 @@ import type {GenericStockDataDTO} from "@/models/stocks/GenericStockDataDTO.ts";
 import {mapToGenericStockDataDTO} from "@/adapter/StockAdapter.ts";
 import type {PredictionPoint} from "@/models/stocks/PredictionPoint.ts";
 import type {StockSegment} from "@/models/stocks/StockSegment.ts";
+import type {ModelTarget, PredictorInfo} from "@/models/stocks/PredictorInfo.ts";
+import type {IndicatorChart} from "@/models/stocks/IndicatorChart.ts";
 
 const API_BASE = import.meta.env.VITE_BACKEND_URL || "/archetrade/api";
 
@@ -23,8 +25,12 @@ export class StockService {
         return await res.json();
     }
 
-    async getStockInfo(symbol: string): Promise<GenericStockDataDTO | null> {
-        const url = `${this.baseUrl}/stocks/info?symbol=${encodeURIComponent(symbol)}`;
+    /** `profile` choisit le profil d'insights ("default" si absent) */
+    async getStockInfo(symbol: string, profile?: string): Promise<GenericStockDataDTO | null> {
+        let url = `${this.baseUrl}/stocks/info?symbol=${encodeURIComponent(symbol)}`;
+        if (profile) {
+            url += `&profile=${encodeURIComponent(profile)}`;
+        }
         const res = await fetch(url);
 
         if (!res.ok) {
@@ -36,7 +42,28 @@ export class StockService {
         return rawData ? mapToGenericStockDataDTO(rawData) : null;
     }
 
-    async predictStock(history: StockSegment[], method: string): Promise<PredictionPoint[]> {
+    /** Séries d'indicateurs pour superposition au graphique, ex. names = ["sma:20", "rsi:14"] */
+    async getIndicators(symbol: string, names: string[]): Promise<IndicatorChart> {
+        const url = `${this.baseUrl}/stocks/${encodeURIComponent(symbol)}/indicators?names=${encodeURIComponent(names.join(","))}`;
+        const res = await fetch(url);
+
+        if (!res.ok) {
+            throw new Error(`Erreur : ${res.status}`);
+        }
+        return await res.json();
+    }
+
+    async getPredictors(): Promise<PredictorInfo[]> {
+        const url = `${this.baseUrl}/predictors`;
+        const res = await fetch(url);
+
+        if (!res.ok) {
+            throw new Error(`Erreur : ${res.status}`);
+        }
+        return await res.json();
+    }
+
+    async predictStock(symbol: string, history: StockSegment[], method: string, parameters: Record<string, number> = {}, target: ModelTarget = "price"): Promise<PredictionPoint[]> {
         try {
             const url = `${this.baseUrl}/stock/predict`;
 
@@ -54,16 +81,17 @@ export class StockService {
                 headers: {
                     "Content-Type": "application/json",
                 },
-                body: JSON.stringify({ method, history: predictionHistory }),
+                body: JSON.stringify({ symbol, method, history: predictionHistory, parameters, target }),
             });
 
             if (!response.ok) {
-                throw new Error(`Erreur réseau lors de la prédiction : ${response.status}`);
+                const message = await response.text();
+                throw new Error(`Erreur lors de la prédiction (${response.status}) : ${message}`);
             }
 
-            const result: PredictionPoint[] = await response.json();
+            const result: { method: string; points: PredictionPoint[]; details?: Record<string, unknown> } = await response.json();
             console.log("Réponse prédiction:", result);
-            return result;
+            return result.points;
 
         } catch (err) {
             console.error("Erreur predictStock:", err);